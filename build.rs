@@ -0,0 +1,98 @@
+//! Generates the solution registry of the library from the solutions in `src/bin`.
+//! Each solution is compiled as a module of the library when the `solutions` feature is enabled.
+use std::{env, fs, path::Path};
+
+const SOLUTIONS_DIR: &str = "src/bin";
+
+/// Fields of the registry entry that are set by other macros than `solution!`: field, item, macro.
+const EXTENSIONS: &[(&str, &str, &str)] = &[
+    ("entry_points", "ENTRY_POINTS", "entry_points"),
+    ("generator", "GENERATOR", "generator"),
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed={SOLUTIONS_DIR}");
+
+    let out_dir = env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    let out_dir = Path::new(&out_dir);
+
+    let mut days: Vec<u8> = fs::read_dir(SOLUTIONS_DIR)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let name = entry.ok()?.file_name().into_string().ok()?;
+                    let stem = name.strip_suffix(".rs")?;
+                    let day: u8 = stem.parse().ok()?;
+                    (stem.len() == 2 && (1..=25).contains(&day)).then_some(day)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    days.sort_unstable();
+
+    let mut registry = String::new();
+    let mut entries = vec![];
+
+    for day in &days {
+        let source = fs::read_to_string(Path::new(SOLUTIONS_DIR).join(format!("{day:02}.rs")))
+            .expect("solution file is readable");
+
+        // inner attributes cannot be included into a module, they are moved to the module instead.
+        // their lines are blanked, so that line numbers in panics match the solution file.
+        let attributes: Vec<&str> = source
+            .lines()
+            .filter(|line| line.starts_with("#!["))
+            .collect();
+        let body: Vec<String> = source
+            .lines()
+            .map(|line| {
+                if line.starts_with("#![") {
+                    String::new()
+                } else if line.trim() == "#[cfg(test)]" {
+                    // tests of a solution run with its binary. `cfg(test)` is also set for the tests of
+                    // the library, the always false `cfg(any())` keeps them from running twice.
+                    line.replace("#[cfg(test)]", "#[cfg(any())]")
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect();
+
+        // optional items of a solution, defined by macros next to `solution!`.
+        let extensions: Vec<(&str, &str)> = EXTENSIONS
+            .iter()
+            .filter(|(_, _, makro)| source.contains(&format!("{makro}!")))
+            .map(|(field, item, _)| (*field, *item))
+            .collect();
+        entries.push((*day, extensions));
+
+        let module_path = out_dir.join(format!("day_{day:02}.rs"));
+        fs::write(&module_path, body.join("\n")).expect("OUT_DIR is writable");
+
+        for attribute in attributes {
+            registry.push_str(&attribute.replacen("#![", "#[", 1));
+            registry.push('\n');
+        }
+        registry.push_str(&format!(
+            "mod day_{day:02} {{\n    use crate::template::registry::shim as advent_of_code;\n    include!({:?});\n}}\n\n",
+            module_path.display().to_string()
+        ));
+    }
+
+    registry.push_str("/// Solutions of every day in `src/bin`, ordered by day.\n");
+    registry.push_str("pub static SOLUTIONS: &[Solution] = &[\n");
+    for (day, extensions) in &entries {
+        if extensions.is_empty() {
+            registry.push_str(&format!("    day_{day:02}::SOLUTION,\n"));
+        } else {
+            registry.push_str("    Solution {\n");
+            for (field, item) in extensions {
+                registry.push_str(&format!("        {field}: day_{day:02}::{item},\n"));
+            }
+            registry.push_str(&format!("        ..day_{day:02}::SOLUTION\n    }},\n"));
+        }
+    }
+    registry.push_str("];\n");
+
+    fs::write(out_dir.join("solutions.rs"), registry).expect("OUT_DIR is writable");
+}
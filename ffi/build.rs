@@ -0,0 +1,119 @@
+//! Generates `advent_of_code.h` in `OUT_DIR` from the `extern "C"` functions in `src/lib.rs`.
+//! Doc comments of the functions are carried over to the header. The checked-in copy in `include/`
+//! is kept up to date by the `header` test, as build scripts must not write to the source tree.
+use std::{env, fs, path::PathBuf};
+
+const SOURCE: &str = "src/lib.rs";
+const HEADER: &str = "advent_of_code.h";
+
+/// Maps a rust type of the exported signatures to its C counterpart.
+fn c_type(rust: &str) -> String {
+    match rust.trim() {
+        "" | "()" => "void".into(),
+        "u8" => "uint8_t".into(),
+        "usize" => "size_t".into(),
+        "bool" => "bool".into(),
+        "*const u8" => "const uint8_t *".into(),
+        "*const c_char" => "const char *".into(),
+        "*mut c_char" => "char *".into(),
+        other => panic!("no C type for `{other}`, add it to `c_type` in build.rs"),
+    }
+}
+
+fn declaration(signature: &str) -> String {
+    let (name, rest) = signature
+        .split_once('(')
+        .expect("exported function has parameters");
+    let (params, ret) = rest.split_once(')').expect("parameter list is closed");
+    let ret = ret.trim().trim_start_matches("->").trim_end_matches('{');
+
+    let params: Vec<String> = params
+        .split(',')
+        .filter(|p| !p.trim().is_empty())
+        .map(|param| {
+            let (name, ty) = param.split_once(':').expect("parameter has a type");
+            let ty = c_type(ty);
+            if ty.ends_with('*') {
+                format!("{ty}{}", name.trim())
+            } else {
+                format!("{ty} {}", name.trim())
+            }
+        })
+        .collect();
+
+    let ret = c_type(ret);
+    let separator = if ret.ends_with('*') { "" } else { " " };
+    let params = if params.is_empty() {
+        "void".to_string()
+    } else {
+        params.join(", ")
+    };
+
+    format!("{ret}{separator}{}({params});", name.trim())
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={SOURCE}");
+
+    let source = fs::read_to_string(SOURCE).expect("source is readable");
+    let mut docs: Vec<&str> = vec![];
+    let mut declarations: Vec<String> = vec![];
+
+    let mut lines = source.lines();
+
+    while let Some(line) = lines.next() {
+        if let Some(doc) = line.strip_prefix("///") {
+            docs.push(doc);
+            continue;
+        }
+
+        let signature = line
+            .strip_prefix("pub unsafe extern \"C\" fn ")
+            .or_else(|| line.strip_prefix("pub extern \"C\" fn "));
+
+        if let Some(signature) = signature {
+            // signatures may be wrapped over several lines by rustfmt.
+            let mut signature = signature.to_string();
+            while !signature.contains('{') {
+                let Some(next) = lines.next() else { break };
+                signature.push_str(next.trim());
+            }
+            let signature = signature.replace(",)", ")");
+
+            let mut declaration = String::new();
+            // the safety section is rust specific, the remaining docs describe the function.
+            for doc in docs.iter().take_while(|doc| doc.trim() != "# Safety") {
+                if !doc.trim().is_empty() {
+                    declaration.push_str(&format!("//{doc}\n"));
+                }
+            }
+            declaration.push_str(&self::declaration(&signature));
+            declarations.push(declaration);
+        }
+
+        if !line.starts_with("#[") {
+            docs.clear();
+        }
+    }
+
+    let header = format!(
+        "// Generated by build.rs from src/lib.rs, do not edit.\n\
+         #ifndef ADVENT_OF_CODE_H\n\
+         #define ADVENT_OF_CODE_H\n\n\
+         #include <stdbool.h>\n\
+         #include <stddef.h>\n\
+         #include <stdint.h>\n\n\
+         #ifdef __cplusplus\n\
+         extern \"C\" {{\n\
+         #endif\n\n\
+         {}\n\n\
+         #ifdef __cplusplus\n\
+         }}\n\
+         #endif\n\n\
+         #endif\n",
+        declarations.join("\n\n")
+    );
+
+    let path = PathBuf::from(env::var_os("OUT_DIR").expect("cargo sets OUT_DIR")).join(HEADER);
+    fs::write(path, header).expect("header is writable");
+}
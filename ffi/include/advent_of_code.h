@@ -0,0 +1,32 @@
+// Generated by build.rs from src/lib.rs, do not edit.
+#ifndef ADVENT_OF_CODE_H
+#define ADVENT_OF_CODE_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Runs a part of a day on an input of `len` bytes.
+// Returns the answer as a string that must be released with `aoc_free_string`, or NULL on errors.
+// The reason of an error is available from `aoc_last_error`.
+char *aoc_solve(uint8_t day, uint8_t part, const uint8_t *input, size_t len);
+
+// Releases an answer returned by `aoc_solve`. Does nothing for NULL.
+void aoc_free_string(char *answer);
+
+// Returns the reason of the last failed `aoc_solve` call on this thread, or an empty string.
+// The string is owned by the library and valid until the next call to `aoc_solve` on this thread.
+const char *aoc_last_error(void);
+
+// Returns a bit mask of the parts a day implements, bit 0 for part one and bit 1 for part two.
+uint8_t aoc_parts(uint8_t day);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
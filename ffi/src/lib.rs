@@ -0,0 +1,120 @@
+//! C interface to the solutions of the `advent_of_code` crate.
+//! The header `include/advent_of_code.h` is generated from the exported functions by the build script.
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic;
+
+use advent_of_code::template::Day;
+use advent_of_code::{solve, SolveError};
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+fn set_last_error(message: String) {
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = message);
+}
+
+fn solve_bytes(day: u8, part: u8, input: &[u8]) -> Result<CString, String> {
+    let day = Day::new(day).ok_or_else(|| format!("{day} is not a valid day"))?;
+    let input = std::str::from_utf8(input).map_err(|e| format!("input is not UTF-8: {e}"))?;
+
+    let answer = panic::catch_unwind(|| solve(day, part, input))
+        .map_err(|_| format!("day {day} part {part} panicked"))?
+        .map_err(|e: SolveError| e.to_string())?;
+
+    CString::new(answer).map_err(|_| "answer contains a NUL byte".to_string())
+}
+
+/// Runs a part of a day on an input of `len` bytes.
+/// Returns the answer as a string that must be released with `aoc_free_string`, or NULL on errors.
+/// The reason of an error is available from `aoc_last_error`.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes, or be NULL if `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(day: u8, part: u8, input: *const u8, len: usize) -> *mut c_char {
+    let input = if len == 0 {
+        &[]
+    } else if input.is_null() {
+        set_last_error("input is NULL".into());
+        return std::ptr::null_mut();
+    } else {
+        // SAFETY: the caller guarantees that `input` points to `len` bytes.
+        unsafe { std::slice::from_raw_parts(input, len) }
+    };
+
+    match solve_bytes(day, part, input) {
+        Ok(answer) => answer.into_raw(),
+        Err(message) => {
+            set_last_error(message);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Releases an answer returned by `aoc_solve`. Does nothing for NULL.
+///
+/// # Safety
+/// `answer` must be NULL or a pointer returned by `aoc_solve` that was not released yet.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free_string(answer: *mut c_char) {
+    if !answer.is_null() {
+        // SAFETY: the caller guarantees that `answer` was created by `CString::into_raw`.
+        drop(unsafe { CString::from_raw(answer) });
+    }
+}
+
+/// Returns the reason of the last failed `aoc_solve` call on this thread, or an empty string.
+/// The string is owned by the library and valid until the next call to `aoc_solve` on this thread.
+#[no_mangle]
+pub extern "C" fn aoc_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ptr())
+}
+
+/// Returns a bit mask of the parts a day implements, bit 0 for part one and bit 1 for part two.
+#[no_mangle]
+pub extern "C" fn aoc_parts(day: u8) -> u8 {
+    Day::new(day)
+        .and_then(advent_of_code::template::registry::get)
+        .map_or(0, |solution| {
+            solution
+                .part_numbers()
+                .fold(0, |mask, part| mask | 1 << (part - 1))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use super::{aoc_free_string, aoc_last_error, aoc_parts, aoc_solve};
+
+    fn last_error() -> String {
+        // SAFETY: `aoc_last_error` always returns a valid string.
+        unsafe { CStr::from_ptr(aoc_last_error()) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn reports_invalid_days() {
+        // SAFETY: an empty input may be NULL.
+        let answer = unsafe { aoc_solve(26, 1, std::ptr::null(), 0) };
+        assert!(answer.is_null());
+        assert_eq!(last_error(), "26 is not a valid day");
+        assert_eq!(aoc_parts(26), 0);
+    }
+
+    #[test]
+    fn reports_invalid_input() {
+        let input = [0xff, 0xfe];
+        // SAFETY: `input` has two bytes.
+        let answer = unsafe { aoc_solve(1, 1, input.as_ptr(), input.len()) };
+        assert!(answer.is_null());
+        assert!(last_error().starts_with("input is not UTF-8"));
+        // SAFETY: NULL is ignored.
+        unsafe { aoc_free_string(answer) };
+    }
+}
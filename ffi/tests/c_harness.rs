@@ -0,0 +1,94 @@
+//! Compiles `harness.c` against the shared library and the generated header and compares its answers with the rust API.
+#![cfg(unix)]
+
+use std::env::consts::{DLL_PREFIX, DLL_SUFFIX};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use advent_of_code::{solutions, solve};
+
+/// Directory of the build artifacts, e.g. `target/debug`. Test binaries live in its `deps` folder.
+fn artifact_dir() -> PathBuf {
+    let exe = std::env::current_exe().unwrap();
+    exe.parent().and_then(Path::parent).unwrap().to_path_buf()
+}
+
+fn compile_harness(name: &str) -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let artifacts = artifact_dir();
+    let library = artifacts.join(format!("{DLL_PREFIX}advent_of_code_ffi{DLL_SUFFIX}"));
+    assert!(library.exists(), "missing {}", library.display());
+
+    let harness = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let status = Command::new(std::env::var("CC").unwrap_or_else(|_| "cc".into()))
+        .arg(manifest_dir.join("tests").join("harness.c"))
+        .arg("-I")
+        .arg(env!("OUT_DIR"))
+        .arg(&library)
+        .arg(format!("-Wl,-rpath,{}", artifacts.display()))
+        .arg("-o")
+        .arg(&harness)
+        .status()
+        .expect("a C compiler is installed");
+    assert!(status.success(), "harness failed to compile");
+
+    harness
+}
+
+/// Runs the harness and returns whether it succeeded along with the last line of its output.
+/// Solutions may print to stdout themselves, the harness prints its result last.
+fn run(harness: &Path, args: &[&str]) -> (bool, String) {
+    let output = Command::new(harness).args(args).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    (
+        output.status.success(),
+        stdout.lines().last().unwrap_or_default().to_string(),
+    )
+}
+
+#[test]
+fn c_harness_matches_rust_api() {
+    let harness = compile_harness("harness");
+    let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("../data/examples");
+
+    for solution in solutions() {
+        let day = solution.day;
+        let input_path = examples.join(format!("{day}.txt"));
+        let Ok(example) = std::fs::read_to_string(&input_path) else {
+            continue;
+        };
+
+        let mask: u8 = solution.part_numbers().map(|p| 1 << (p - 1)).sum();
+        assert_eq!(
+            run(&harness, &["parts", &day.to_string()]),
+            (true, mask.to_string())
+        );
+
+        for part in solution.part_numbers() {
+            let expected = match solve(day, part, &example) {
+                Ok(answer) => (true, answer),
+                Err(e) => (false, format!("error: {e}")),
+            };
+            let input = input_path.to_string_lossy();
+            let actual = run(
+                &harness,
+                &["solve", &day.to_string(), &part.to_string(), &input],
+            );
+            assert_eq!(actual, expected, "day {day} part {part}");
+        }
+    }
+}
+
+#[test]
+fn c_harness_reports_errors() {
+    let harness = compile_harness("harness_errors");
+    let input_path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("empty.txt");
+    std::fs::write(&input_path, "").unwrap();
+    let input = input_path.to_string_lossy();
+
+    assert_eq!(
+        run(&harness, &["solve", "26", "1", &input]),
+        (false, "error: 26 is not a valid day".into())
+    );
+    assert_eq!(run(&harness, &["parts", "26"]), (true, "0".into()));
+}
@@ -0,0 +1,56 @@
+// Calls the C interface of the solutions from C.
+// Usage:
+//   harness parts <day>                      prints the bit mask of implemented parts
+//   harness solve <day> <part> <input file>  prints the answer, or `error: <reason>`
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "advent_of_code.h"
+
+static uint8_t *read_file(const char *path, size_t *len) {
+    FILE *file = fopen(path, "rb");
+    if (file == NULL) {
+        return NULL;
+    }
+
+    fseek(file, 0, SEEK_END);
+    long size = ftell(file);
+    fseek(file, 0, SEEK_SET);
+
+    uint8_t *buffer = malloc(size > 0 ? (size_t)size : 1);
+    *len = fread(buffer, 1, (size_t)size, file);
+    fclose(file);
+    return buffer;
+}
+
+int main(int argc, char **argv) {
+    if (argc == 3 && strcmp(argv[1], "parts") == 0) {
+        printf("%d\n", aoc_parts((uint8_t)atoi(argv[2])));
+        return 0;
+    }
+
+    if (argc != 5 || strcmp(argv[1], "solve") != 0) {
+        fprintf(stderr, "usage: %s parts <day> | solve <day> <part> <input file>\n", argv[0]);
+        return 2;
+    }
+
+    size_t len = 0;
+    uint8_t *input = read_file(argv[4], &len);
+    if (input == NULL) {
+        fprintf(stderr, "could not read %s\n", argv[4]);
+        return 2;
+    }
+
+    char *answer = aoc_solve((uint8_t)atoi(argv[2]), (uint8_t)atoi(argv[3]), input, len);
+    free(input);
+
+    if (answer == NULL) {
+        printf("error: %s\n", aoc_last_error());
+        return 1;
+    }
+
+    printf("%s\n", answer);
+    aoc_free_string(answer);
+    return 0;
+}
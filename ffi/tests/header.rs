@@ -0,0 +1,22 @@
+//! Keeps the checked-in header in sync with the header generated by `build.rs`.
+use std::fs;
+use std::path::Path;
+
+const GENERATED: &str = include_str!(concat!(env!("OUT_DIR"), "/advent_of_code.h"));
+
+#[test]
+fn checked_in_header_is_up_to_date() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("include")
+        .join("advent_of_code.h");
+
+    if fs::read_to_string(&path).ok().as_deref() == Some(GENERATED) {
+        return;
+    }
+
+    fs::write(&path, GENERATED).expect("header is writable");
+    panic!(
+        "\"{}\" was out of date and has been regenerated, commit the updated header.",
+        path.display()
+    );
+}
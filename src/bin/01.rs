@@ -29,9 +29,8 @@ fn solve(input: &str) -> (i32, i32) {
 
     for (x, y) in zip(a, b) {
         dist += (x - y).abs();
-        match counts.get(&x) {
-            Some(&count) => sum += x * count,
-            _ => (),
+        if let Some(&count) = counts.get(&x) {
+            sum += x * count;
         }
     }
 
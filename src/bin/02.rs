@@ -11,20 +11,20 @@ fn report_matrix(input: &str) -> Vec<Vec<i64>> {
         .collect()
 }
 
-fn criteria(input: &Vec<i64>) -> bool {
+fn criteria(input: &[i64]) -> bool {
     (input.windows(2).all(|w| w[0] < w[1]) || // all increasing
      input.windows(2).all(|w| w[0] > w[1])) && // or decreasing
     input.windows(2).all(|w| (w[0] - w[1]).abs() >= 1) && // adj diff
         input.windows(2).all(|w| (w[0] - w[1]).abs() <= 3)
 }
 
-fn single_bad_level(level: &Vec<i64>) -> bool {
+fn single_bad_level(level: &[i64]) -> bool {
     if criteria(level) {
         return true;
     }
 
     for (i, _) in level.iter().enumerate() {
-        let mut level_copy = level.clone();
+        let mut level_copy = level.to_vec();
         level_copy.remove(i);
         if criteria(&level_copy) {
             return true;
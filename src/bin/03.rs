@@ -9,12 +9,12 @@ fn parse_mul(input: &str) -> Vec<i32> {
     // Try to find a match in the input string
     pattern
         .captures_iter(input)
-        .filter_map(|captures| {
+        .map(|captures| {
             // Parse the captured numbers into i32
             let x = captures[1].parse::<i32>().unwrap();
             let y = captures[2].parse::<i32>().unwrap();
 
-            Some(x * y)
+            x * y
         })
         .collect()
 }
@@ -41,7 +41,7 @@ fn parse_cond_mul(input: &str) -> Vec<i32> {
                         let y = captures[3].parse::<i32>().ok()?;
                         return Some(x * y);
                     }
-                    return None;
+                    None
                 }
                 "do()" => {
                     mul_enabled = true;
@@ -34,8 +34,8 @@ fn find_xmas_in_grid(grid: &[Vec<char>]) -> usize {
 
     for row in 0..rows {
         for col in 0..cols {
-            for &(dr, dc) in &directions {
-                if search_word_in_direction(grid, target, row, col, dr, dc, rows, cols) {
+            for &direction in &directions {
+                if search_word_in_direction(grid, target, (row, col), direction) {
                     count += 1;
                 }
             }
@@ -48,13 +48,11 @@ fn find_xmas_in_grid(grid: &[Vec<char>]) -> usize {
 fn search_word_in_direction(
     grid: &[Vec<char>],
     word: &str,
-    start_row: usize,
-    start_col: usize,
-    row_dir: i32,
-    col_dir: i32,
-    rows: usize,
-    cols: usize,
+    (start_row, start_col): (usize, usize),
+    (row_dir, col_dir): (i32, i32),
 ) -> bool {
+    let rows = grid.len();
+    let cols = grid[0].len();
     let word_chars: Vec<char> = word.chars().collect();
 
     for (i, &ch) in word_chars.iter().enumerate() {
@@ -145,6 +145,55 @@ fn find_starting_position(grid: &[Vec<char>]) -> (usize, usize, (i32, i32)) {
     panic!("No starting position found");
 }
 
+/// Generates a square map of side `size` in which the guard walks an outward spiral before leaving the map,
+/// so the length of its path grows with the area. Obstacles off the path are scattered in about one of
+/// twenty cells, they never block the guard.
+fn generate(rng: &mut advent_of_code::template::differential::Rng, size: usize) -> String {
+    let side = size.max(1) as i32;
+    let in_map = |row: i32, col: i32| row >= 0 && row < side && col >= 0 && col < side;
+
+    let mut grid = vec![vec!['.'; side as usize]; side as usize];
+    let mut on_path = vec![vec![false; side as usize]; side as usize];
+
+    // rings of the spiral are at least two cells apart, so turning obstacles stay off the path.
+    let gap = rng.range(2..=4) as i32;
+    let (mut row, mut col) = (side / 2, side / 2);
+    grid[row as usize][col as usize] = '^';
+    let (mut dr, mut dc) = (-1, 0);
+
+    'walk: for leg in 0.. {
+        for _ in 0..gap * (leg / 2 + 1) {
+            on_path[row as usize][col as usize] = true;
+            if !in_map(row + dr, col + dc) {
+                break 'walk;
+            }
+            row += dr;
+            col += dc;
+        }
+
+        on_path[row as usize][col as usize] = true;
+        if !in_map(row + dr, col + dc) {
+            break;
+        }
+        grid[(row + dr) as usize][(col + dc) as usize] = '#';
+        (dr, dc) = (dc, -dr);
+    }
+
+    for (grid_row, path_row) in grid.iter_mut().zip(&on_path) {
+        for (cell, &on_path) in grid_row.iter_mut().zip(path_row) {
+            if *cell == '.' && !on_path && rng.chance(0.05) {
+                *cell = '#';
+            }
+        }
+    }
+
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+advent_of_code::generator!(generate);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,4 +209,19 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(6));
     }
+
+    #[test]
+    fn test_generated_path_grows_with_the_area() {
+        use advent_of_code::template::differential::Rng;
+
+        let small = part_one(&generate(&mut Rng::new(0), 32)).unwrap();
+        let large = part_one(&generate(&mut Rng::new(0), 64)).unwrap();
+        assert!(large > 3 * small, "{small} -> {large}");
+
+        let grid: Vec<Vec<char>> = generate(&mut Rng::new(0), 64)
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect();
+        assert!(!creates_loop(&grid));
+    }
 }
@@ -3,7 +3,7 @@ advent_of_code::solution!(7);
 pub fn part_one(input: &str) -> Option<u64> {
     let sum: u64 = input
         .lines()
-        .map(|line| parse_input(line))
+        .map(parse_input)
         .filter(|(target, nums)| valid_combination(*target, nums))
         .map(|(target, _)| target)
         .sum();
@@ -13,7 +13,7 @@ pub fn part_one(input: &str) -> Option<u64> {
 pub fn part_two(input: &str) -> Option<u64> {
     let sum: u64 = input
         .lines()
-        .map(|line| parse_input(line))
+        .map(parse_input)
         .filter(|(target, nums)| valid_combination_with_concat(*target, nums))
         .map(|(target, _)| target)
         .sum();
@@ -108,6 +108,77 @@ fn concatenate_numbers(left: u64, right: u64) -> u64 {
     concatenated.parse().unwrap_or(u64::MAX) // Return MAX on overflow
 }
 
+/// Slow reference for part one that tries every operator recursively.
+#[cfg(test)]
+fn part_one_reference(input: &str) -> Option<u64> {
+    Some(reference_sum(input, false))
+}
+
+/// Slow reference for part two that tries every operator recursively.
+#[cfg(test)]
+fn part_two_reference(input: &str) -> Option<u64> {
+    Some(reference_sum(input, true))
+}
+
+#[cfg(test)]
+fn reference_sum(input: &str, with_concat: bool) -> u64 {
+    fn reachable(target: u64, value: u64, nums: &[u64], with_concat: bool) -> bool {
+        let Some((&next, rest)) = nums.split_first() else {
+            return value == target;
+        };
+
+        reachable(target, value + next, rest, with_concat)
+            || reachable(target, value * next, rest, with_concat)
+            || (with_concat
+                && reachable(
+                    target,
+                    format!("{value}{next}").parse().unwrap(),
+                    rest,
+                    with_concat,
+                ))
+    }
+
+    input
+        .lines()
+        .map(parse_input)
+        .filter(|(target, nums)| match nums.split_first() {
+            Some((&first, rest)) => reachable(*target, first, rest, with_concat),
+            None => false,
+        })
+        .map(|(target, _)| target)
+        .sum()
+}
+
+/// Generates `size` equations of up to five small numbers. Half of the targets are reachable.
+#[cfg(test)]
+fn generate(rng: &mut advent_of_code::template::differential::Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let nums: Vec<u64> = (0..rng.range(1..=5)).map(|_| rng.range(1..=20)).collect();
+            let target = if rng.chance(0.5) {
+                nums[1..]
+                    .iter()
+                    .fold(nums[0], |value, &next| match rng.range(0..=2) {
+                        0 => value + next,
+                        1 => value * next,
+                        _ => format!("{value}{next}").parse().unwrap(),
+                    })
+            } else {
+                rng.range(1..=10_000)
+            };
+
+            let nums: Vec<String> = nums.iter().map(u64::to_string).collect();
+            format!("{target}: {}\n", nums.join(" "))
+        })
+        .collect()
+}
+
+advent_of_code::differential! {
+    generate,
+    part_one: part_one_reference,
+    part_two: part_two_reference,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,12 +6,11 @@ use std::collections::HashSet;
 pub fn part_one(input: &str) -> Option<u64> {
     let grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
     let mut nodes: HashMap<char, Vec<(usize, usize)>> = HashMap::new();
-    for row in 0..grid.len() {
-        for col in 0..grid[0].len() {
-            let ch = grid[row][col];
+    for (row, line) in grid.iter().enumerate() {
+        for (col, &ch) in line.iter().enumerate() {
             if ch != '.' {
                 // Only store antenna positions, not empty spaces
-                nodes.entry(ch).or_insert_with(Vec::new).push((row, col));
+                nodes.entry(ch).or_default().push((row, col));
             }
         }
     }
@@ -21,7 +20,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     let mut antinodes: HashSet<(i32, i32)> = HashSet::new();
 
     // For each frequency, check all unique pair combinations of antennas
-    for (_frequency, positions) in &nodes {
+    for positions in nodes.values() {
         for i in 0..positions.len() {
             for j in i + 1..positions.len() {
                 let (r1, c1) = (positions[i].0 as i32, positions[i].1 as i32);
@@ -54,12 +53,11 @@ pub fn part_one(input: &str) -> Option<u64> {
 pub fn part_two(input: &str) -> Option<u64> {
     let grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
     let mut nodes: HashMap<char, Vec<(usize, usize)>> = HashMap::new();
-    for row in 0..grid.len() {
-        for col in 0..grid[0].len() {
-            let ch = grid[row][col];
+    for (row, line) in grid.iter().enumerate() {
+        for (col, &ch) in line.iter().enumerate() {
             if ch != '.' {
                 // Only store antenna positions, not empty spaces
-                nodes.entry(ch).or_insert_with(Vec::new).push((row, col));
+                nodes.entry(ch).or_default().push((row, col));
             }
         }
     }
@@ -69,7 +67,7 @@ pub fn part_two(input: &str) -> Option<u64> {
     let mut antinodes: HashSet<(i32, i32)> = HashSet::new();
 
     // For each frequency, check all unique pair combinations of antennas
-    for (_frequency, positions) in &nodes {
+    for positions in nodes.values() {
         for i in 0..positions.len() {
             for j in i + 1..positions.len() {
                 let (r1, c1) = (positions[i].0 as i32, positions[i].1 as i32);
@@ -5,8 +5,8 @@ pub fn part_one(input: &str) -> Option<u64> {
     let mut file_id = 0;
     let mut file_block_map: Vec<Option<usize>> = Vec::new();
 
-    for i in 0..disk_map.len() {
-        let file_size = disk_map[i].to_digit(10).unwrap() as usize;
+    for (i, digit) in disk_map.iter().enumerate() {
+        let file_size = digit.to_digit(10).unwrap() as usize;
         if i % 2 == 0 {
             let mut file = vec![Some(file_id); file_size];
             file_block_map.append(&mut file);
@@ -30,8 +30,8 @@ pub fn part_one(input: &str) -> Option<u64> {
     }
 
     let mut checksum = 0;
-    for i in 0..file_block_map.len() {
-        if let Some(id) = file_block_map[i] {
+    for (i, block) in file_block_map.iter().enumerate() {
+        if let Some(id) = block {
             checksum += id * i;
         }
     }
@@ -50,8 +50,8 @@ pub fn part_two(input: &str) -> Option<u64> {
 
     // Build the initial disk layout and track free spaces and file positions
     let mut pos = 0;
-    for i in 0..disk_map.len() {
-        let size = disk_map[i].to_digit(10).unwrap() as usize;
+    for (i, digit) in disk_map.iter().enumerate() {
+        let size = digit.to_digit(10).unwrap() as usize;
         if i % 2 == 0 {
             // File
             file_positions.insert(file_id, (pos, size));
@@ -84,7 +84,7 @@ pub fn part_two(input: &str) -> Option<u64> {
 
     // Helper function to move a whole file
     fn move_file(
-        file_block_map: &mut Vec<Option<usize>>,
+        file_block_map: &mut [Option<usize>],
         free_spaces: &mut HashMap<usize, usize>,
         file_id: usize,
         file_start: usize,
@@ -92,14 +92,10 @@ pub fn part_two(input: &str) -> Option<u64> {
         target_pos: usize,
     ) {
         // Clear old position
-        for i in file_start..file_start + file_size {
-            file_block_map[i] = None;
-        }
+        file_block_map[file_start..file_start + file_size].fill(None);
 
         // Place file at new position
-        for i in target_pos..target_pos + file_size {
-            file_block_map[i] = Some(file_id);
-        }
+        file_block_map[target_pos..target_pos + file_size].fill(Some(file_id));
 
         // Update free space tracking
         let old_free_size = free_spaces.remove(&target_pos).unwrap();
@@ -130,8 +126,8 @@ pub fn part_two(input: &str) -> Option<u64> {
 
     // Calculate checksum
     let mut checksum = 0;
-    for i in 0..file_block_map.len() {
-        if let Some(id) = file_block_map[i] {
+    for (i, block) in file_block_map.iter().enumerate() {
+        if let Some(id) = block {
             checksum += id * i;
         }
     }
@@ -139,6 +135,93 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(checksum as u64)
 }
 
+/// Slow reference for part one that moves single blocks between two cursors.
+#[cfg(test)]
+fn part_one_reference(input: &str) -> Option<u64> {
+    let mut blocks = reference_blocks(input);
+
+    let (mut free, mut last) = (0, blocks.len());
+    loop {
+        while free < blocks.len() && blocks[free].is_some() {
+            free += 1;
+        }
+        while last > 0 && blocks[last - 1].is_none() {
+            last -= 1;
+        }
+        if last == 0 || free >= last - 1 {
+            break;
+        }
+        blocks.swap(free, last - 1);
+    }
+
+    Some(reference_checksum(&blocks))
+}
+
+/// Slow reference for part two that searches the whole disk for a gap before every move.
+#[cfg(test)]
+fn part_two_reference(input: &str) -> Option<u64> {
+    let mut blocks = reference_blocks(input);
+    let files = blocks.iter().flatten().max().map_or(0, |id| id + 1);
+
+    for id in (0..files).rev() {
+        let start = blocks.iter().position(|block| *block == Some(id)).unwrap();
+        let len = blocks.iter().filter(|block| **block == Some(id)).count();
+
+        let gap = (0..start)
+            .find(|&gap| gap + len <= start && blocks[gap..gap + len].iter().all(Option::is_none));
+
+        if let Some(gap) = gap {
+            for i in 0..len {
+                blocks.swap(gap + i, start + i);
+            }
+        }
+    }
+
+    Some(reference_checksum(&blocks))
+}
+
+#[cfg(test)]
+fn reference_blocks(input: &str) -> Vec<Option<usize>> {
+    input
+        .trim()
+        .chars()
+        .enumerate()
+        .flat_map(|(i, c)| {
+            let id = (i % 2 == 0).then_some(i / 2);
+            std::iter::repeat_n(id, c.to_digit(10).unwrap() as usize)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+fn reference_checksum(blocks: &[Option<usize>]) -> u64 {
+    blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(i, block)| block.map(|id| (i * id) as u64))
+        .sum()
+}
+
+/// Generates a disk map of `size` files with gaps in between.
+fn generate(rng: &mut advent_of_code::template::differential::Rng, size: usize) -> String {
+    let mut disk_map = String::new();
+    for i in 0..size {
+        if i > 0 {
+            disk_map.push_str(&rng.range(0..=9).to_string());
+        }
+        disk_map.push_str(&rng.range(1..=9).to_string());
+    }
+    disk_map + "\n"
+}
+
+advent_of_code::differential! {
+    generate,
+    part_one: part_one_reference,
+    part_two: part_two_reference,
+}
+
+advent_of_code::generator!(generate);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,11 +2,16 @@ use std::collections::HashMap;
 
 advent_of_code::solution!(11);
 
+advent_of_code::entry_points! {
+    /// Counts the stones after a number of blinks.
+    solve(input, blinks: u32) => solve(input, blinks),
+}
+
 fn count_digits(n: u64) -> u32 {
     // checked_ilog10 returns Some(floor(log10(n))) or None for 0.
     // unwrap_or(0) handles the case for n=0 (which has 1 digit).
     // Adding 1 gives the total number of digits.
-    (n.checked_ilog10().unwrap_or(0) + 1) as u32
+    n.checked_ilog10().unwrap_or(0) + 1
 }
 
 fn solve(input: &str, blinks: u32) -> Option<u64> {
@@ -30,7 +35,7 @@ fn solve(input: &str, blinks: u32) -> Option<u64> {
             if stone == 0 {
                 // Rule 1: 0 becomes 1
                 *new_counts.entry(1).or_insert(0) += count;
-            } else if total_digits % 2 == 0 {
+            } else if total_digits.is_multiple_of(2) {
                 // Rule 2: Split stone with even number of digits
                 let divisor = 10_u64.pow(total_digits / 2);
                 let first_half = stone / divisor;
@@ -11,7 +11,7 @@ enum Direction {
 }
 
 fn flood_fill(
-    grid: &Vec<Vec<char>>,
+    grid: &[Vec<char>],
     start_row: usize,
     start_col: usize,
     visited: &mut HashSet<(usize, usize)>,
@@ -58,7 +58,7 @@ fn flood_fill(
 }
 
 fn flood_fill_with_edges(
-    grid: &Vec<Vec<char>>,
+    grid: &[Vec<char>],
     start_row: usize,
     start_col: usize,
     visited: &mut HashSet<(usize, usize)>,
@@ -117,7 +117,7 @@ fn count_sides(edges: Vec<(usize, usize, Direction)>) -> u64 {
 
     // Group edges by direction
     for (row, col, dir) in edges {
-        sides_by_direction.entry(dir).or_insert_with(Vec::new).push((row, col));
+        sides_by_direction.entry(dir).or_default().push((row, col));
     }
 
     let mut total_sides = 0u64;
@@ -129,7 +129,7 @@ fn count_sides(edges: Vec<(usize, usize, Direction)>) -> u64 {
                 // Horizontal edges: group by row, sort by col, count segments
                 let mut by_row: HashMap<usize, Vec<usize>> = HashMap::new();
                 for (row, col) in positions {
-                    by_row.entry(row).or_insert_with(Vec::new).push(col);
+                    by_row.entry(row).or_default().push(col);
                 }
 
                 for (_row, mut cols) in by_row {
@@ -147,7 +147,7 @@ fn count_sides(edges: Vec<(usize, usize, Direction)>) -> u64 {
                 // Vertical edges: group by col, sort by row, count segments
                 let mut by_col: HashMap<usize, Vec<usize>> = HashMap::new();
                 for (row, col) in positions {
-                    by_col.entry(col).or_insert_with(Vec::new).push(row);
+                    by_col.entry(col).or_default().push(row);
                 }
 
                 for (_col, mut rows) in by_col {
@@ -226,6 +226,29 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(total_cost)
 }
 
+/// Generates a square garden of side `size`. Plots often copy the plant above or to the left, which grows regions.
+fn generate(rng: &mut advent_of_code::template::differential::Rng, size: usize) -> String {
+    let mut grid = vec![vec!['A'; size]; size];
+
+    for row in 0..size {
+        for col in 0..size {
+            grid[row][col] = if row > 0 && rng.chance(0.4) {
+                grid[row - 1][col]
+            } else if col > 0 && rng.chance(0.5) {
+                grid[row][col - 1]
+            } else {
+                *rng.pick(&['A', 'B', 'C', 'D', 'E'])
+            };
+        }
+    }
+
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+advent_of_code::generator!(generate);
+
 #[cfg(test)]
 mod tests {
     use super::*;
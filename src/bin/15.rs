@@ -37,7 +37,7 @@ fn direction_delta(dir: char) -> (i32, i32) {
     }
 }
 
-fn simulate_move(grid: &mut Vec<Vec<char>>, robot: &mut (usize, usize), dir: char) {
+fn simulate_move(grid: &mut [Vec<char>], robot: &mut (usize, usize), dir: char) {
     let (dr, dc) = direction_delta(dir);
     let (robot_row, robot_col) = *robot;
 
@@ -219,11 +219,8 @@ fn push_wide_box(grid: &mut Vec<Vec<char>>, box_row: usize, box_col: usize, dr:
             _ => {}
         }
 
-        match grid[new_row][right_col] {
-            '[' => {
-                boxes_to_push.insert((new_row, right_col));
-            }
-            _ => {}
+        if grid[new_row][right_col] == '[' {
+            boxes_to_push.insert((new_row, right_col));
         }
 
         for &(r, c) in &boxes_to_push {
@@ -251,13 +248,11 @@ fn simulate_wide_move(grid: &mut Vec<Vec<char>>, robot: &mut (usize, usize), dir
             grid[new_row][new_col] = '@';
             *robot = (new_row, new_col);
         }
-        '[' => {
-            if can_push_wide_box(grid, new_row, new_col, dr, dc) {
-                push_wide_box(grid, new_row, new_col, dr, dc);
-                grid[robot_row][robot_col] = '.';
-                grid[new_row][new_col] = '@';
-                *robot = (new_row, new_col);
-            }
+        '[' if can_push_wide_box(grid, new_row, new_col, dr, dc) => {
+            push_wide_box(grid, new_row, new_col, dr, dc);
+            grid[robot_row][robot_col] = '.';
+            grid[new_row][new_col] = '@';
+            *robot = (new_row, new_col);
         }
         ']' => {
             let box_left_col = new_col - 1;
@@ -61,7 +61,9 @@ impl PartialOrd for State {
     }
 }
 
-fn parse_input(input: &str) -> (Vec<Vec<char>>, (usize, usize), (usize, usize)) {
+type Position = (usize, usize);
+
+fn parse_input(input: &str) -> (Vec<Vec<char>>, Position, Position) {
     let grid: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
 
     let mut start = (0, 0);
@@ -2,6 +2,14 @@ use std::collections::HashMap;
 
 advent_of_code::solution!(21);
 
+advent_of_code::entry_points! {
+    /// Sum of the complexities with a number of directional robots in between.
+    solve(input, robots: usize) => solve(input, robots),
+    /// Shortest button sequence for a code on the numeric keypad at a depth of robots.
+    min_length_for_sequence(input, sequence: String, depth: usize) =>
+        min_length_for_sequence(&sequence, depth, true, &mut HashMap::new()),
+}
+
 type Pos = (i32, i32);
 
 fn get_numeric_keypad_pos(key: char) -> Pos {
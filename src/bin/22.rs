@@ -70,9 +70,9 @@ fn find_best_sequence(input: &str) -> u64 {
             let sequence = (changes[i], changes[i + 1], changes[i + 2], changes[i + 3]);
 
             // Only count the first occurrence of each sequence for this buyer
-            if !seen.contains_key(&sequence) {
+            if let std::collections::hash_map::Entry::Vacant(e) = seen.entry(sequence) {
                 let price = prices[i + 4]; // Price after the 4 changes
-                seen.insert(sequence, price);
+                e.insert(price);
                 *sequence_totals.entry(sequence).or_insert(0) += price;
             }
         }
@@ -1,3 +1,6 @@
 pub mod template;
 
+#[cfg(feature = "solutions")]
+pub use template::registry::{solutions, solve, SolveError};
+
 // Use this file to add helper functions and additional modules.
@@ -1,12 +1,19 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
-use args::{parse, AppArguments};
-
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
+use advent_of_code::template::commands::{
+    all, download, leaderboard, new_year, read, report, scaffold, serve, solve, stats, status,
+    time, tui,
+};
+#[cfg(feature = "encryption")]
+use advent_of_code::template::commands::{decrypt, encrypt};
+#[cfg(feature = "solutions")]
+use advent_of_code::template::commands::{repl, scale, serve_stdio, shrink};
+use advent_of_code::template::load_config;
+use args::{parse, AppArguments};
 
 mod args {
+    #[cfg(feature = "solutions")]
+    use advent_of_code::template::commands::shrink;
     use advent_of_code::template::Day;
     use std::process;
 
@@ -16,28 +23,98 @@ mod args {
         },
         Read {
             day: Day,
+            part: Option<u8>,
+            pager: bool,
         },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            all_inputs: bool,
         },
         All {
             release: bool,
+            all_inputs: bool,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
         },
+        Report {
+            html: String,
+        },
+        Status {
+            record: bool,
+            check: bool,
+        },
+        Stats {
+            update_readme: bool,
+        },
+        Tui,
+        #[cfg(feature = "encryption")]
+        Encrypt,
+        #[cfg(feature = "encryption")]
+        Decrypt {
+            overwrite: bool,
+        },
+        Leaderboard {
+            path: String,
+        },
+        Serve {
+            host: String,
+            port: u16,
+        },
+        NewYear {
+            year: u16,
+        },
+        #[cfg(feature = "solutions")]
+        Repl {
+            day: Day,
+            input: Option<String>,
+            then: Option<String>,
+        },
+        #[cfg(feature = "solutions")]
+        ServeStdio,
+        #[cfg(feature = "solutions")]
+        Shrink {
+            day: Day,
+            part: Option<u8>,
+            input: Option<String>,
+            against: Option<String>,
+            timeout: Option<u64>,
+            overwrite: bool,
+        },
+        #[cfg(feature = "solutions")]
+        ShrinkRun {
+            day: Day,
+            part: u8,
+        },
+        #[cfg(feature = "solutions")]
+        Scale {
+            day: Day,
+            part: Option<u8>,
+            sizes: Option<Vec<usize>>,
+            max_millis: u64,
+        },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+            fake_now: Option<String>,
+        },
+    }
+
+    /// Parses a comma separated list of sizes, e.g. `8,16,32`.
+    #[cfg(feature = "solutions")]
+    fn parse_sizes(sizes: &str) -> Result<Vec<usize>, std::num::ParseIntError> {
+        sizes.split(',').map(|size| size.trim().parse()).collect()
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
@@ -46,6 +123,7 @@ mod args {
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                all_inputs: args.contains("--all-inputs"),
             },
             Some("time") => {
                 let all = args.contains("--all");
@@ -57,13 +135,45 @@ mod args {
                     store,
                 }
             }
+            Some("report") => AppArguments::Report {
+                html: args.value_from_str("--html")?,
+            },
+            Some("status") => AppArguments::Status {
+                record: args.contains("--record"),
+                check: args.contains("--check"),
+            },
+            Some("stats") => AppArguments::Stats {
+                update_readme: args.contains("--update-readme"),
+            },
+            Some("tui") => AppArguments::Tui,
+            #[cfg(feature = "encryption")]
+            Some("encrypt") => AppArguments::Encrypt,
+            #[cfg(feature = "encryption")]
+            Some("decrypt") => AppArguments::Decrypt {
+                overwrite: args.contains("--overwrite"),
+            },
+            Some("leaderboard") => AppArguments::Leaderboard {
+                path: args.free_from_str()?,
+            },
+            Some("serve") => AppArguments::Serve {
+                host: args
+                    .opt_value_from_str("--host")?
+                    .unwrap_or_else(|| "127.0.0.1".into()),
+                port: args.opt_value_from_str("--port")?.unwrap_or(3000),
+            },
+            Some("new-year") => AppArguments::NewYear {
+                year: args.free_from_str()?,
+            },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
+                part: args.opt_value_from_str("--part")?,
+                pager: args.contains("--pager"),
                 day: args.free_from_str()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
+                template: args.opt_value_from_str("--template")?,
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
@@ -73,9 +183,42 @@ mod args {
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                all_inputs: args.contains("--all-inputs"),
+            },
+            #[cfg(feature = "solutions")]
+            Some("repl") => AppArguments::Repl {
+                input: args.opt_value_from_str("--input")?,
+                then: args.opt_value_from_str("--then")?,
+                day: args.free_from_str()?,
+            },
+            #[cfg(feature = "solutions")]
+            Some("serve-stdio") => AppArguments::ServeStdio,
+            #[cfg(feature = "solutions")]
+            Some("shrink") => AppArguments::Shrink {
+                part: args.opt_value_from_str("--part")?,
+                input: args.opt_value_from_str("--input")?,
+                against: args.opt_value_from_str("--against")?,
+                timeout: args.opt_value_from_str("--timeout")?,
+                overwrite: args.contains("--overwrite"),
+                day: args.free_from_str()?,
+            },
+            #[cfg(feature = "solutions")]
+            Some(shrink::RUN_COMMAND) => AppArguments::ShrinkRun {
+                day: args.free_from_str()?,
+                part: args.free_from_str()?,
+            },
+            #[cfg(feature = "solutions")]
+            Some("scale") => AppArguments::Scale {
+                part: args.opt_value_from_str("--part")?,
+                sizes: args.opt_value_from_fn("--sizes", parse_sizes)?,
+                max_millis: args.opt_value_from_str("--max-millis")?.unwrap_or(1000),
+                day: args.free_from_str()?,
             },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+                fake_now: args.opt_value_from_str("--fake-now")?,
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -101,44 +244,98 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
-                day,
-                download,
-                overwrite,
-            } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
+        Ok(args) => {
+            if !reports_config_errors(&args) {
+                if let Err(e) = load_config() {
+                    eprintln!("{e}");
+                    std::process::exit(1);
                 }
             }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
-        },
+            run(args);
+        }
     };
 }
+
+/// Interactive commands report an invalid config file to their client instead of exiting.
+fn reports_config_errors(args: &AppArguments) -> bool {
+    match args {
+        AppArguments::Tui => true,
+        #[cfg(feature = "solutions")]
+        AppArguments::ServeStdio => true,
+        _ => false,
+    }
+}
+
+fn run(args: AppArguments) {
+    match args {
+        AppArguments::All {
+            release,
+            all_inputs,
+        } => all::handle(release, all_inputs),
+        AppArguments::Time { day, all, store } => time::handle(day, all, store),
+        AppArguments::Report { html } => report::handle(&html),
+        AppArguments::Status { record, check } => status::handle(record, check),
+        AppArguments::Stats { update_readme } => stats::handle(update_readme),
+        AppArguments::Tui => tui::handle(),
+        #[cfg(feature = "encryption")]
+        AppArguments::Encrypt => encrypt::handle(),
+        #[cfg(feature = "encryption")]
+        AppArguments::Decrypt { overwrite } => decrypt::handle(overwrite),
+        AppArguments::Leaderboard { path } => leaderboard::handle(&path),
+        AppArguments::Serve { host, port } => serve::handle(&host, port),
+        AppArguments::NewYear { year } => new_year::handle(year),
+        AppArguments::Download { day } => download::handle(day),
+        AppArguments::Read { day, part, pager } => read::handle(day, part, pager),
+        AppArguments::Scaffold {
+            day,
+            download,
+            overwrite,
+            template,
+        } => {
+            if download {
+                download::handle(day);
+            }
+            scaffold::handle(day, overwrite, template.as_deref());
+        }
+        AppArguments::Solve {
+            day,
+            release,
+            dhat,
+            submit,
+            all_inputs,
+        } => solve::handle(day, release, dhat, submit, all_inputs),
+        #[cfg(feature = "solutions")]
+        AppArguments::Repl { day, input, then } => {
+            repl::handle(day, input.as_deref(), then.as_deref());
+        }
+        #[cfg(feature = "solutions")]
+        AppArguments::ServeStdio => serve_stdio::handle(),
+        #[cfg(feature = "solutions")]
+        AppArguments::Shrink {
+            day,
+            part,
+            input,
+            against,
+            timeout,
+            overwrite,
+        } => shrink::handle(
+            day,
+            part,
+            input.as_deref(),
+            against.as_deref(),
+            timeout,
+            overwrite,
+        ),
+        #[cfg(feature = "solutions")]
+        AppArguments::ShrinkRun { day, part } => shrink::run_part(day, part),
+        #[cfg(feature = "solutions")]
+        AppArguments::Scale {
+            day,
+            part,
+            sizes,
+            max_millis,
+        } => scale::handle(day, part, sizes, max_millis),
+        #[cfg(feature = "today")]
+        AppArguments::Today { wait, fake_now } => today::handle(wait, fake_now.as_deref()),
+    }
+}
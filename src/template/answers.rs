@@ -0,0 +1,289 @@
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{config, Day};
+
+fn file_path() -> PathBuf {
+    let config = config::get();
+    config.path(&config.answers_file)
+}
+
+/// Represents the accepted answers for a single day.
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    /// Parts whose answer was accepted for a different input than the current one.
+    pub stale: [bool; 2],
+}
+
+/// Represents the accepted answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(file_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(file_path())
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Returns the accepted answer for a part, if known.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.day == day)?;
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the answer of a part was accepted for a different input.
+    pub fn is_stale(&self, day: Day, part: u8) -> bool {
+        self.data
+            .iter()
+            .find(|a| a.day == day)
+            .is_some_and(|a| matches!(part, 1 | 2) && a.stale[usize::from(part) - 1])
+    }
+
+    /// Marks the answers of a day as stale, returns whether any answer was marked.
+    pub fn mark_stale(&mut self, day: Day) -> bool {
+        let Some(answer) = self.data.iter_mut().find(|a| a.day == day) else {
+            return false;
+        };
+
+        let mut marked = false;
+        for (i, value) in [&answer.part_1, &answer.part_2].into_iter().enumerate() {
+            if value.is_some() && !answer.stale[i] {
+                answer.stale[i] = true;
+                marked = true;
+            }
+        }
+        marked
+    }
+
+    /// Records the accepted answer for a part, replacing a previous one.
+    pub fn set(&mut self, day: Day, part: u8, value: &str) {
+        let index = match self.data.iter().position(|a| a.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer {
+                    day,
+                    part_1: None,
+                    part_2: None,
+                    stale: [false, false],
+                });
+                self.data.sort_unstable_by_key(|a| a.day);
+                self.data.iter().position(|a| a.day == day).unwrap()
+            }
+        };
+
+        match part {
+            1 => self.data[index].part_1 = Some(value.into()),
+            2 => self.data[index].part_2 = Some(value.into()),
+            _ => return,
+        }
+
+        self.data[index].stale[usize::from(part) - 1] = false;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        map.insert(
+            "part_1".into(),
+            value
+                .part_1
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "part_2".into(),
+            value
+                .part_2
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "stale".into(),
+            JsonValue::Array(value.stale.map(JsonValue::Boolean).to_vec()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        let stale = match json.get("stale") {
+            None => [false, false],
+            Some(v) => {
+                let parts = v
+                    .get::<Vec<JsonValue>>()
+                    .filter(|parts| parts.len() == 2)
+                    .ok_or("Expected answer.stale to be an array of two booleans.")?;
+                let part = |i: usize| {
+                    parts[i]
+                        .get::<bool>()
+                        .copied()
+                        .ok_or("Expected answer.stale to be an array of two booleans.")
+                };
+                [part(0)?, part(1)?]
+            }
+        };
+
+        Ok(Answer {
+            day,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+            stale,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Answers;
+    use crate::day;
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "data": [{ "day": "01", "part_1": "42", "part_2": null }] }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(day!(1), 1), Some("42"));
+        assert_eq!(answers.get(day!(1), 2), None);
+        assert_eq!(answers.get(day!(2), 1), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "day": "26", "part_1": null, "part_2": null }] }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn sets_answers_in_day_order() {
+        let mut answers = Answers::default();
+        answers.set(day!(4), 2, "7");
+        answers.set(day!(1), 1, "3");
+        answers.set(day!(4), 1, "5");
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].day, day!(1));
+        assert_eq!(answers.get(day!(4), 1), Some("5"));
+        assert_eq!(answers.get(day!(4), 2), Some("7"));
+    }
+
+    #[test]
+    fn marks_answers_stale_until_replaced() {
+        let mut answers = Answers::default();
+        answers.set(day!(2), 1, "10");
+
+        assert!(answers.mark_stale(day!(2)));
+        assert!(!answers.mark_stale(day!(2)));
+        assert!(!answers.mark_stale(day!(3)));
+        assert!(answers.is_stale(day!(2), 1));
+        assert!(!answers.is_stale(day!(2), 2));
+
+        let json = tinyjson::JsonValue::from(answers.clone())
+            .stringify()
+            .unwrap();
+        assert!(Answers::try_from(json).unwrap().is_stale(day!(2), 1));
+
+        answers.set(day!(2), 1, "12");
+        assert!(!answers.is_stale(day!(2), 1));
+    }
+
+    #[test]
+    fn roundtrips_through_json() {
+        let mut answers = Answers::default();
+        answers.set(day!(3), 2, "1234");
+        let json = tinyjson::JsonValue::from(answers).stringify().unwrap();
+        let parsed = Answers::try_from(json).unwrap();
+        assert_eq!(parsed.get(day!(3), 1), None);
+        assert_eq!(parsed.get(day!(3), 2), Some("1234"));
+    }
+}
@@ -0,0 +1,210 @@
+/// Module that generates the files of an archived year. Archives live in `years/<year>/` as a member
+/// of the cargo workspace with their own `aoc.toml`, so commands run from that directory use the
+/// archived solutions and data. Binaries are prefixed with the year to keep names unique, e.g. `2024-01`.
+use std::path::Path;
+
+use regex::Regex;
+
+use crate::template::{config::Config, Day};
+
+/// Directory of an archived year, relative to the project root.
+pub fn archive_dir(year: u16) -> String {
+    format!("years/{year}")
+}
+
+pub fn bin_prefix(year: u16) -> String {
+    format!("{year}-")
+}
+
+/// Returns the `[dependencies]` entries of a manifest.
+fn dependencies(manifest: &str) -> Vec<&str> {
+    manifest
+        .lines()
+        .skip_while(|line| line.trim() != "[dependencies]")
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with('['))
+        .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .collect()
+}
+
+/// Builds the manifest of an archived year. Dependencies of the project are carried over so
+/// archived solutions keep compiling.
+pub fn manifest(year: u16, root_manifest: &str, days: &[Day]) -> String {
+    let mut lines = vec![
+        "[package]".to_string(),
+        format!("name = \"advent_of_code_{year}\""),
+        "version = \"0.1.0\"".into(),
+        "edition = \"2021\"".into(),
+        "publish = false".into(),
+        "autobins = false".into(),
+        String::new(),
+        "[features]".into(),
+        "dhat-heap = [\"dhat\", \"advent_of_code/dhat-heap\"]".into(),
+        String::new(),
+        "[dependencies]".into(),
+        "advent_of_code = { path = \"../..\" }".into(),
+    ];
+
+    lines.extend(
+        dependencies(root_manifest)
+            .into_iter()
+            .map(String::from)
+            .filter(|line| !line.starts_with("advent_of_code ")),
+    );
+
+    for day in days {
+        lines.push(String::new());
+        lines.push("[[bin]]".into());
+        lines.push(format!("name = \"{}{day}\"", bin_prefix(year)));
+        lines.push(format!("path = \"src/bin/{day}.rs\""));
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// Adds a member to the workspace of a manifest, creating the workspace if necessary.
+pub fn add_workspace_member(manifest: &str, member: &str) -> String {
+    let re = Regex::new(r"(?m)^members\s*=\s*\[").expect("valid regex");
+
+    match re.find(manifest) {
+        Some(m) => {
+            let rest = &manifest[m.end()..];
+            let separator = if rest.trim_start().starts_with(']') {
+                ""
+            } else {
+                ", "
+            };
+            format!("{}\"{member}\"{separator}{rest}", &manifest[..m.end()])
+        }
+        None => format!(
+            "{}\n\n[workspace]\nmembers = [\"{member}\"]\n",
+            manifest.trim_end()
+        ),
+    }
+}
+
+/// Builds the cargo config of an archived year. Its aliases run the command line of the project
+/// package, as the archive package only contains solutions.
+pub fn cargo_config(root_config: &str) -> String {
+    root_config.replace("\"run ", "\"run --package advent_of_code ")
+}
+
+/// Replaces the `year` setting of a config file, adding it if missing.
+pub fn set_year(config: &str, year: u16) -> String {
+    let re = Regex::new(r"(?m)^year\s*=.*$").expect("valid regex");
+
+    if re.is_match(config) {
+        re.replace(config, format!("year = {year}")).into_owned()
+    } else {
+        format!("year = {year}\n{config}")
+    }
+}
+
+/// Builds the config file of an archived year. Paths are relative to the archive, the key file
+/// for encrypted inputs is shared with the project.
+pub fn config(year: u16, config: &Config) -> String {
+    let path = |value: &str| format!("{value:?}");
+
+    let key_file = if config.key_file.starts_with("~/") || Path::new(&config.key_file).is_absolute()
+    {
+        config.key_file.clone()
+    } else {
+        format!("../../{}", config.key_file)
+    };
+
+    [
+        format!("# Archived solutions of {year}. Run commands from this directory, e.g. `cargo solve 01`."),
+        format!("year = {year}"),
+        format!("bin_prefix = {}", path(&bin_prefix(year))),
+        String::new(),
+        "[paths]".into(),
+        format!("inputs = {}", path(&config.inputs_dir)),
+        format!("examples = {}", path(&config.examples_dir)),
+        format!("puzzles = {}", path(&config.puzzles_dir)),
+        format!("timings = {}", path(&config.timings_file)),
+        format!("answers = {}", path(&config.answers_file)),
+        format!("solve_times = {}", path(&config.solve_times_file)),
+        format!("fingerprints = {}", path(&config.fingerprints_file)),
+        format!("chart = {}", path(&config.chart_file)),
+        String::new(),
+        "[encryption]".into(),
+        format!("key_file = {}", path(&key_file)),
+        String::new(),
+    ]
+    .join("\n")
+}
+
+/// Builds the readme of an archived year containing its benchmark table.
+pub fn readme(year: u16, table: &str) -> String {
+    format!("# 🎄 Advent of Code {year}\n\n{table}\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{add_workspace_member, cargo_config, config, manifest, set_year};
+    use crate::day;
+    use crate::template::config::Config;
+
+    #[test]
+    fn builds_manifests() {
+        let root = "[package]\nname = \"advent_of_code\"\n\n[dependencies]\n\n# Template dependencies\nregex = \"1.11.1\"\ndhat = { version = \"0.3.3\", optional = true }\n\n[workspace]\nmembers = []\n";
+        let manifest = manifest(2024, root, &[day!(1), day!(2)]);
+
+        assert!(manifest.contains("name = \"advent_of_code_2024\""));
+        assert!(manifest.contains("advent_of_code = { path = \"../..\" }"));
+        assert!(manifest.contains("regex = \"1.11.1\""));
+        assert!(manifest.contains("dhat = { version = \"0.3.3\", optional = true }"));
+        assert!(!manifest.contains("members"));
+        assert!(manifest.contains("[[bin]]\nname = \"2024-02\"\npath = \"src/bin/02.rs\""));
+    }
+
+    #[test]
+    fn adds_workspace_members() {
+        let manifest = "[package]\nname = \"aoc\"\n";
+        let manifest = add_workspace_member(manifest, "years/2023");
+        assert!(manifest.ends_with("\n\n[workspace]\nmembers = [\"years/2023\"]\n"));
+
+        let manifest = add_workspace_member(&manifest, "years/2024");
+        assert!(manifest.contains("members = [\"years/2024\", \"years/2023\"]"));
+
+        assert_eq!(
+            add_workspace_member("[workspace]\nmembers = []\n", "years/2024"),
+            "[workspace]\nmembers = [\"years/2024\"]\n"
+        );
+    }
+
+    #[test]
+    fn builds_cargo_configs() {
+        assert_eq!(
+            cargo_config("[alias]\nsolve = \"run --quiet --release -- solve\"\n"),
+            "[alias]\nsolve = \"run --package advent_of_code --quiet --release -- solve\"\n"
+        );
+    }
+
+    #[test]
+    fn sets_years() {
+        assert_eq!(set_year("# c\nyear = 2024\n", 2025), "# c\nyear = 2025\n");
+        assert_eq!(set_year("[paths]\n", 2025), "year = 2025\n[paths]\n");
+    }
+
+    #[test]
+    fn builds_archive_configs() {
+        let project = Config::parse(PathBuf::from("/aoc"), "year = 2024").unwrap();
+        let archive =
+            Config::parse(PathBuf::from("/aoc/years/2024"), &config(2024, &project)).unwrap();
+
+        assert_eq!(archive.year, Some(2024));
+        assert_eq!(archive.bin_name(day!(3)), "2024-03");
+        assert_eq!(
+            archive.input_path(day!(3)),
+            PathBuf::from("/aoc/years/2024/data/inputs/03.txt")
+        );
+        assert_eq!(
+            archive.path(&archive.key_file),
+            PathBuf::from("/aoc/years/2024/../../.aoc-key")
+        );
+    }
+}
@@ -1,5 +1,13 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{all_days, config, run_multi::run_multi};
+
+pub fn handle(is_release: bool, all_inputs: bool) {
+    // external solutions receive the default input only, their answers cannot be compared per input.
+    if all_inputs && all_days().any(|day| config::get().external_commands(day).next().is_some()) {
+        eprintln!("`--all-inputs` cannot be combined with external solutions. Run `cargo all` to compare them on the default inputs.");
+        process::exit(1);
+    }
+
+    run_multi(&all_days().collect(), is_release, false, all_inputs);
 }
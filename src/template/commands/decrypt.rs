@@ -0,0 +1,63 @@
+use std::{fs, process};
+
+use crate::template::encryption;
+
+pub fn handle(overwrite: bool) {
+    let key = match encryption::read_key() {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("Failed to read encryption key: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut decrypted = 0;
+
+    for (folder, _) in encryption::protected_folders() {
+        let files = match encryption::files_with_extension(&folder, encryption::EXTENSION) {
+            Ok(files) => files,
+            Err(e) => {
+                eprintln!("Failed to list \"{}\": {e}", folder.display());
+                continue;
+            }
+        };
+
+        for path in files {
+            let target = path.with_extension("");
+
+            let plaintext = match fs::read(&path)
+                .map_err(encryption::EncryptionError::from)
+                .and_then(|data| encryption::decrypt(&key, &data))
+            {
+                Ok(plaintext) => plaintext,
+                Err(e) => {
+                    eprintln!("Failed to decrypt \"{}\": {e}", path.display());
+                    continue;
+                }
+            };
+
+            match fs::read(&target) {
+                Ok(existing) if existing == plaintext => continue,
+                Ok(existing) if !existing.is_empty() && !overwrite => {
+                    eprintln!(
+                        "Skipped \"{}\" because it differs from the encrypted file. Pass `--overwrite` to replace it.",
+                        target.display()
+                    );
+                    continue;
+                }
+                _ => {}
+            }
+
+            match fs::write(&target, plaintext) {
+                Ok(()) => {
+                    println!("Decrypted \"{}\"", target.display());
+                    decrypted += 1;
+                }
+                Err(e) => eprintln!("Failed to write \"{}\": {e}", target.display()),
+            }
+        }
+    }
+
+    println!("---");
+    println!("🔓 Decrypted {decrypted} file(s).");
+}
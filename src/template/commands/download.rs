@@ -1,4 +1,4 @@
-use crate::template::{aoc_cli, Day};
+use crate::template::{aoc_cli, solve_times, Day};
 use std::process;
 
 pub fn handle(day: Day) {
@@ -11,4 +11,6 @@ pub fn handle(day: Day) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
+
+    solve_times::record_start(day);
 }
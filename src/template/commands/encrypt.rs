@@ -0,0 +1,89 @@
+use std::{fs, path::Path, process};
+
+use crate::template::config;
+use crate::template::encryption::{self, encrypted_path};
+
+pub fn handle() {
+    let key = match encryption::read_or_create_key() {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("Failed to read encryption key: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut encrypted = 0;
+
+    for (folder, extension) in encryption::protected_folders() {
+        let files = match encryption::files_with_extension(&folder, extension) {
+            Ok(files) => files,
+            Err(e) => {
+                eprintln!("Failed to list \"{}\": {e}", folder.display());
+                continue;
+            }
+        };
+
+        for path in files {
+            let plaintext = match fs::read(&path) {
+                Ok(plaintext) if !plaintext.is_empty() => plaintext,
+                Ok(_) => continue,
+                Err(e) => {
+                    eprintln!("Failed to read \"{}\": {e}", path.display());
+                    continue;
+                }
+            };
+
+            // encryption uses a random nonce, only rewrite files whose content changed to keep diffs small.
+            let target = encrypted_path(&path);
+            let unchanged = fs::read(&target)
+                .ok()
+                .and_then(|existing| encryption::decrypt(&key, &existing).ok())
+                .is_some_and(|existing| existing == plaintext);
+
+            if unchanged {
+                continue;
+            }
+
+            match fs::write(&target, encryption::encrypt(&key, &plaintext)) {
+                Ok(()) => {
+                    println!("Encrypted \"{}\"", path.display());
+                    encrypted += 1;
+                }
+                Err(e) => eprintln!("Failed to write \"{}\": {e}", target.display()),
+            }
+        }
+    }
+
+    println!("---");
+    println!("🔒 Encrypted {encrypted} file(s). Commit the `.enc` files and share the key file privately.");
+
+    let ignores = missing_ignores();
+    if !ignores.is_empty() {
+        println!();
+        println!(
+            "Add these lines to .gitignore to keep the key and the plaintext files out of git:"
+        );
+        for line in ignores {
+            println!("{line}");
+        }
+    }
+}
+
+/// Patterns for the key file and the plaintext files that are not in `.gitignore` yet.
+fn missing_ignores() -> Vec<String> {
+    let config = config::get();
+    let relative = |path: &Path| {
+        path.strip_prefix(&config.root)
+            .ok()
+            .map(|path| format!("/{}", path.display()))
+    };
+
+    let mut patterns: Vec<String> = relative(&encryption::key_path()).into_iter().collect();
+    for (folder, extension) in encryption::protected_folders() {
+        patterns.extend(relative(&folder).map(|folder| format!("{folder}/**/*.{extension}")));
+    }
+
+    let gitignore = fs::read_to_string(config.root.join(".gitignore")).unwrap_or_default();
+    patterns.retain(|pattern| !gitignore.lines().any(|line| line.trim() == pattern));
+    patterns
+}
@@ -0,0 +1,29 @@
+use std::{
+    fs,
+    io::{self, Read},
+    process,
+};
+
+use crate::template::leaderboard::Leaderboard;
+
+/// Prints a private leaderboard. Pass `-` to read the JSON from stdin.
+pub fn handle(path: &str) {
+    let contents = if path == "-" {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents).map(|_| contents)
+    } else {
+        fs::read_to_string(path)
+    };
+
+    let leaderboard = contents
+        .map_err(|e| e.to_string())
+        .and_then(Leaderboard::try_from);
+
+    match leaderboard {
+        Ok(leaderboard) => println!("{}", leaderboard.render()),
+        Err(e) => {
+            eprintln!("Failed to read leaderboard: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,6 +1,27 @@
 pub mod all;
+#[cfg(feature = "encryption")]
+pub mod decrypt;
 pub mod download;
+#[cfg(feature = "encryption")]
+pub mod encrypt;
+pub mod leaderboard;
+pub mod new_year;
 pub mod read;
+#[cfg(feature = "solutions")]
+pub mod repl;
+pub mod report;
 pub mod scaffold;
+#[cfg(feature = "solutions")]
+pub mod scale;
+pub mod serve;
+#[cfg(feature = "solutions")]
+pub mod serve_stdio;
+#[cfg(feature = "solutions")]
+pub mod shrink;
 pub mod solve;
+pub mod stats;
+pub mod status;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
+pub mod tui;
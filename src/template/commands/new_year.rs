@@ -0,0 +1,159 @@
+use std::{fs, io, path::Path, process};
+
+use crate::template::readme_benchmarks;
+use crate::template::{archive, config, Day};
+
+pub fn handle(new_year: u16) {
+    let config = config::get();
+
+    let Some(year) = config.year else {
+        eprintln!("Set `year` in aoc.toml to the year you want to archive.");
+        process::exit(1);
+    };
+
+    if year == new_year {
+        eprintln!("The project is already set up for {year}.");
+        process::exit(1);
+    }
+
+    let archive_dir = config.path(&archive::archive_dir(year));
+    if archive_dir.exists() {
+        eprintln!(
+            "Archive \"{}\" exists already. Move it away to archive {year} again.",
+            archive_dir.display()
+        );
+        process::exit(1);
+    }
+
+    if let Err(e) = create_archive(year, new_year, &archive_dir) {
+        eprintln!("Failed to archive {year}: {e}");
+        process::exit(1);
+    }
+
+    println!("---");
+    println!(
+        "🎄 Archived {year} to \"{}\", the project is set up for {new_year}.",
+        archive_dir.display()
+    );
+    println!(
+        "Run the archived solutions from their directory, e.g. `cd {} && cargo all`.",
+        archive::archive_dir(year)
+    );
+}
+
+fn create_archive(year: u16, new_year: u16, archive_dir: &Path) -> Result<(), io::Error> {
+    let config = config::get();
+
+    // solutions.
+    let solutions_dir = config.path(config::SOLUTIONS_DIR);
+    let days = solution_days(&solutions_dir)?;
+    let archive_bins = archive_dir.join("src").join("bin");
+    fs::create_dir_all(&archive_bins)?;
+
+    for day in &days {
+        move_path(
+            &config.bin_path(*day),
+            &archive_bins.join(format!("{day}.rs")),
+        )?;
+    }
+    println!(
+        "Moved {} solution(s) to \"{}\"",
+        days.len(),
+        archive_bins.display()
+    );
+
+    // data folders are recreated empty, stored files start over for the new year.
+    for folder in [
+        &config.inputs_dir,
+        &config.examples_dir,
+        &config.puzzles_dir,
+    ] {
+        if move_relative(folder, archive_dir)? {
+            let path = config.path(folder);
+            fs::create_dir_all(&path)?;
+            fs::write(path.join(".keep"), "")?;
+        }
+    }
+
+    for file in [
+        &config.timings_file,
+        &config.answers_file,
+        &config.solve_times_file,
+        &config.fingerprints_file,
+        &config.chart_file,
+    ] {
+        move_relative(file, archive_dir)?;
+    }
+
+    // the benchmark table of the old year moves to the readme of the archive.
+    let readme_path = config.path(&config.readme_file);
+    let mut readme = fs::read_to_string(&readme_path)?;
+    let table = readme_benchmarks::take_table(&mut readme)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{e:?}")))?;
+    fs::write(&readme_path, readme)?;
+    fs::write(archive_dir.join("README.md"), archive::readme(year, &table))?;
+    println!("Reset the benchmark table in \"{}\"", readme_path.display());
+
+    let root_manifest_path = config.root.join("Cargo.toml");
+    let root_manifest = fs::read_to_string(&root_manifest_path)?;
+    fs::write(
+        archive_dir.join("Cargo.toml"),
+        archive::manifest(year, &root_manifest, &days),
+    )?;
+    fs::write(
+        &root_manifest_path,
+        archive::add_workspace_member(&root_manifest, &archive::archive_dir(year)),
+    )?;
+    fs::write(archive_dir.join("aoc.toml"), archive::config(year, config))?;
+
+    let cargo_config_path = config.root.join(".cargo").join("config.toml");
+    if let Ok(cargo_config) = fs::read_to_string(&cargo_config_path) {
+        fs::create_dir_all(archive_dir.join(".cargo"))?;
+        fs::write(
+            archive_dir.join(".cargo").join("config.toml"),
+            archive::cargo_config(&cargo_config),
+        )?;
+    }
+
+    let config_path = config.root.join("aoc.toml");
+    let project_config = fs::read_to_string(&config_path).unwrap_or_default();
+    fs::write(&config_path, archive::set_year(&project_config, new_year))?;
+    println!("Set `year = {new_year}` in \"{}\"", config_path.display());
+
+    Ok(())
+}
+
+/// Days that have a solution in the solutions directory.
+fn solution_days(solutions_dir: &Path) -> Result<Vec<Day>, io::Error> {
+    let mut days: Vec<Day> = fs::read_dir(solutions_dir)?
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.strip_suffix(".rs")?.parse().ok()
+        })
+        .collect();
+    days.sort();
+    Ok(days)
+}
+
+/// Moves a path relative to the project root to the same relative path in the archive.
+/// Returns whether the path existed. Absolute paths are shared between years and are not moved.
+fn move_relative(relative: &str, archive_dir: &Path) -> Result<bool, io::Error> {
+    if relative.starts_with("~/") || Path::new(relative).is_absolute() {
+        return Ok(false);
+    }
+
+    let source = config::get().path(relative);
+    if !source.exists() {
+        return Ok(false);
+    }
+
+    move_path(&source, &archive_dir.join(relative))?;
+    Ok(true)
+}
+
+fn move_path(source: &Path, target: &Path) -> Result<(), io::Error> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(source, target)
+}
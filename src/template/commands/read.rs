@@ -1,8 +1,37 @@
-use std::process;
+use std::{
+    env,
+    io::Write,
+    process::{self, Command, Stdio},
+};
 
-use crate::template::{aoc_cli, Day};
+use crate::template::{aoc_cli, config, encryption, markdown, Day};
 
-pub fn handle(day: Day) {
+pub fn handle(day: Day, part: Option<u8>, pager: bool) {
+    let Ok(puzzle) = encryption::read_to_string(&config::get().puzzle_path(day)) else {
+        // the puzzle was not downloaded yet, fetch it with aoc-cli.
+        read_remote(day);
+        return;
+    };
+
+    let puzzle = match part {
+        Some(part) => match markdown::select_part(&puzzle, part) {
+            Some(section) => section,
+            None => {
+                eprintln!("Part {part} is not part of the downloaded puzzle. If you solved part 1, run `cargo download {day}` to fetch part 2.");
+                process::exit(1);
+            }
+        },
+        None => puzzle,
+    };
+
+    let rendered = markdown::render(&puzzle);
+
+    if !pager || !page(&rendered) {
+        println!("{rendered}");
+    }
+}
+
+fn read_remote(day: Day) {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
@@ -13,3 +42,29 @@ pub fn handle(day: Day) {
         process::exit(1);
     };
 }
+
+/// Shows the output in `$PAGER`, defaulting to `less -R`. Returns `false` if no pager could be started.
+fn page(output: &str) -> bool {
+    let pager = env::var("PAGER").unwrap_or_else(|_| "less -R".into());
+    let mut parts = pager.split_whitespace();
+
+    let Some(program) = parts.next() else {
+        return false;
+    };
+
+    let Ok(mut child) = Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn()
+    else {
+        return false;
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // the pager may be closed before all output was written.
+        let _ = writeln!(stdin, "{output}");
+    }
+
+    let _ = child.wait();
+    true
+}
@@ -0,0 +1,136 @@
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::process::{self, Command};
+
+use crate::template::registry;
+use crate::template::repl::{self, Command as ReplCommand};
+use crate::template::{config, inputs, Day, ANSI_BOLD, ANSI_RESET};
+
+pub fn handle(day: Day, input: Option<&str>, then: Option<&str>) {
+    let solution = registry::require(day).unwrap_or_else(|e| {
+        eprintln!("{e}.");
+        process::exit(1);
+    });
+
+    // the executable is replaced by rebuilds, so its path is resolved before.
+    let exe = env::current_exe().unwrap_or_else(|e| {
+        eprintln!("Failed to locate executable: {e}");
+        process::exit(1);
+    });
+
+    let mut input_name = input.unwrap_or(inputs::DEFAULT_INPUT).to_string();
+    let mut input = load(day, &input_name).unwrap_or_default();
+    let mut last: Option<String> = None;
+
+    println!("Type `:help` to list parts, entry points and commands.");
+
+    if let Some(then) = then {
+        println!("> {then}");
+        match repl::parse(then) {
+            Ok(command) => {
+                evaluate(solution, &input, &command);
+                last = Some(then.to_string());
+            }
+            Err(e) => eprintln!("{e}"),
+        }
+    }
+
+    let stdin = io::stdin();
+    loop {
+        print!("> ");
+        let _ = io::stdout().flush();
+
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+
+        match repl::parse(&line) {
+            Ok(ReplCommand::Empty) => {}
+            Ok(ReplCommand::Quit) => break,
+            Ok(ReplCommand::Help) => repl::help(solution)
+                .iter()
+                .for_each(|line| println!("{line}")),
+            Ok(ReplCommand::Input(name)) => {
+                if let Some(content) = load(day, &name) {
+                    input = content;
+                    input_name = name;
+                }
+            }
+            Ok(ReplCommand::Rebuild) => rebuild(&exe, day, &input_name, last.as_deref()),
+            Ok(command) => {
+                evaluate(solution, &input, &command);
+                last = Some(line.trim().to_string());
+            }
+            Err(e) => eprintln!("{e}"),
+        }
+    }
+}
+
+fn load(day: Day, name: &str) -> Option<String> {
+    match repl::read_input(day, name) {
+        Ok(input) => {
+            println!(
+                "Loaded input \"{name}\" ({} line(s)).",
+                input.lines().count()
+            );
+            Some(input)
+        }
+        Err(e) => {
+            eprintln!("Failed to load input \"{name}\": {e}");
+            None
+        }
+    }
+}
+
+fn evaluate(solution: &registry::Solution, input: &str, command: &ReplCommand) {
+    match repl::call(solution, input, command) {
+        Ok((answer, duration)) => {
+            if answer.contains('\n') {
+                println!("▼ ({duration:.1?})\n{answer}");
+            } else {
+                println!("{ANSI_BOLD}{answer}{ANSI_RESET} ({duration:.1?})");
+            }
+        }
+        Err(e) => eprintln!("{e}"),
+    }
+}
+
+/// Rebuilds the solutions with the profile of the running session and replaces the session with the new build.
+/// The input is kept and the last call is repeated. If the build fails, the session continues.
+fn rebuild(exe: &Path, day: Day, input_name: &str, last: Option<&str>) {
+    let mut build = Command::new("cargo");
+    build
+        .args(["build", "--quiet", "--features", "solutions"])
+        .args(["--bin", "advent_of_code"])
+        .current_dir(&config::get().root);
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+
+    if !build.status().is_ok_and(|status| status.success()) {
+        eprintln!("Build failed, the session continues with the previous build.");
+        return;
+    }
+
+    let mut session = Command::new(exe);
+    session
+        .args(["repl", "--input", input_name])
+        .args(last.map(|last| ["--then", last]).into_iter().flatten())
+        .arg(day.to_string());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let e = session.exec();
+        eprintln!("Failed to restart: {e}");
+    }
+
+    #[cfg(not(unix))]
+    match session.status() {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => eprintln!("Failed to restart: {e}"),
+    }
+}
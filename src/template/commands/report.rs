@@ -0,0 +1,15 @@
+use std::{path::Path, process};
+
+use crate::template::html_report;
+use crate::template::timings::Timings;
+
+pub fn handle(html_path: &str) {
+    let timings = Timings::read_from_file();
+
+    if let Err(e) = html_report::write(&timings, Path::new(html_path)) {
+        eprintln!("Failed to write report: {e}");
+        process::exit(1);
+    }
+
+    println!("🎄 Successfully wrote report to \"{html_path}\".");
+}
@@ -1,15 +1,16 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::{Path, PathBuf},
     process,
 };
 
-use crate::template::Day;
+use crate::template::{config, encryption, puzzle, solve_times, Day};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
+fn safe_create_file(path: &Path, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
         file.create(true);
@@ -19,7 +20,7 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -27,10 +28,113 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Reads a named template from the templates directory. Without a name, the template configured
+/// in `aoc.toml` is used, falling back to the built-in template.
+fn read_template(name: Option<&str>) -> Result<String, String> {
+    let config = config::get();
+
+    let Some(name) = name else {
+        return Ok(fs::read_to_string(config.path(&config.template))
+            .unwrap_or_else(|_| MODULE_TEMPLATE.into()));
+    };
+
+    let folder = config.path(&config.templates_dir);
+    fs::read_to_string(folder.join(format!("{name}.txt"))).map_err(|_| {
+        let available = available_templates(&folder);
+        if available.is_empty() {
+            format!("template \"{name}\" not found in \"{}\".", folder.display())
+        } else {
+            format!(
+                "template \"{name}\" not found. Available templates: {}.",
+                available.join(", ")
+            )
+        }
+    })
+}
+
+fn available_templates(folder: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(folder)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let name = entry.ok()?.file_name().into_string().ok()?;
+                    name.strip_suffix(".txt").map(String::from)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+/// Formats an expected answer as an assertion value for a template returning `Option<u64>`.
+/// Answers that do not fit a `u64`, e.g. negative numbers, are left as `None`.
+fn expected_value(answer: Option<&String>) -> String {
+    match answer {
+        Some(answer) if answer.parse::<u64>().is_ok() => format!("Some({answer})"),
+        _ => "None".into(),
+    }
+}
+
+/// Formats an expected answer as an assertion value for a template returning `Option<String>`.
+fn expected_string_value(answer: Option<&String>) -> String {
+    match answer {
+        Some(answer) => format!("Some({answer:?}.to_string())"),
+        None => "None".into(),
+    }
+}
+
+pub fn handle(day: Day, overwrite: bool, template: Option<&str>) {
+    let config = config::get();
+    let input_path = config.input_path(day);
+    let example_path = config.example_path(day);
+    let module_path = config.bin_path(day);
+
+    let template = match read_template(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to read template: {e}");
+            process::exit(1);
+        }
+    };
+
+    // pre-fill examples and answers if the puzzle description was downloaded already.
+    let puzzle = encryption::read_to_string(&config.puzzle_path(day)).unwrap_or_default();
+    let examples = puzzle::extract_examples(&puzzle);
+    let primary_example = puzzle::primary_example(&puzzle);
+    let [part_one_answer, part_two_answer] = puzzle::extract_answers(&puzzle);
+
+    // the example of the detected answer is used by the generated tests, other candidates get an
+    // `ex` suffix for use with `read_file_suffix`, e.g. `01-ex2.txt`. Part numbers like `01-2.txt`
+    // are left to examples of part two.
+    let mut number = 1;
+    let example_paths: Vec<PathBuf> = (0..examples.len())
+        .map(|i| {
+            if i == primary_example {
+                example_path.clone()
+            } else {
+                number += 1;
+                config
+                    .data_folder("examples")
+                    .join(format!("{day}-ex{number}.txt"))
+            }
+        })
+        .collect();
+
+    let example_files = if example_paths.is_empty() {
+        vec![&example_path]
+    } else {
+        example_paths.iter().collect()
+    }
+    .into_iter()
+    .map(|path| {
+        path.strip_prefix(&config.root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    })
+    .collect::<Vec<_>>()
+    .join(", ");
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -41,12 +145,37 @@ pub fn handle(day: Day, overwrite: bool) {
     };
 
     match file.write_all(
-        MODULE_TEMPLATE
+        template
             .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+            .replace(
+                "%YEAR%",
+                &config.year.map(|year| year.to_string()).unwrap_or_default(),
+            )
+            .replace(
+                "%PUZZLE_TITLE%",
+                &puzzle::extract_title(&puzzle).unwrap_or_else(|| format!("Day {day}")),
+            )
+            .replace("%EXAMPLE_FILES%", &example_files)
+            .replace(
+                "%PART_ONE_ANSWER%",
+                &expected_value(part_one_answer.as_ref()),
+            )
+            .replace(
+                "%PART_TWO_ANSWER%",
+                &expected_value(part_two_answer.as_ref()),
+            )
+            .replace(
+                "%PART_ONE_STRING_ANSWER%",
+                &expected_string_value(part_one_answer.as_ref()),
+            )
+            .replace(
+                "%PART_TWO_STRING_ANSWER%",
+                &expected_string_value(part_two_answer.as_ref()),
+            )
             .as_bytes(),
     ) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
@@ -54,26 +183,56 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    // keep inputs that were downloaded before scaffolding.
+    if fs::metadata(&input_path).is_ok_and(|m| m.len() > 0) {
+        println!("Kept existing input file \"{}\"", input_path.display());
+    } else if encryption::encrypted_path(&input_path).exists() {
+        // an empty plaintext file would shadow the encrypted input.
+        println!("Kept encrypted input file, run `cargo decrypt` to restore the plaintext.");
+    } else {
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{}\"", input_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {e}");
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    }
+
+    if examples.is_empty() {
+        match create_file(&example_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", example_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    for (path, example) in example_paths.iter().zip(&examples) {
+        match fs::write(path, example) {
+            Ok(()) => {
+                println!("Created example file \"{}\" from puzzle", path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    }
+
+    for (part, answer) in [(1, &part_one_answer), (2, &part_two_answer)] {
+        if let Some(answer) = answer {
+            println!("Detected expected example answer for part {part}: {answer}");
         }
     }
 
+    solve_times::record_start(day);
+
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
@@ -0,0 +1,118 @@
+use std::io::{self, Write};
+use std::process;
+use std::time::Duration;
+
+use crate::template::registry;
+use crate::template::scale::{self, Measurement};
+use crate::template::{inputs, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+pub fn handle(day: Day, part: Option<u8>, sizes: Option<Vec<usize>>, max_millis: u64) {
+    let solution = registry::require(day).unwrap_or_else(|e| {
+        eprintln!("{e}.");
+        process::exit(1);
+    });
+
+    let Some(generate) = solution.generator else {
+        eprintln!(
+            "day {day} has no input generator, register one with `advent_of_code::generator!(generate);`."
+        );
+        process::exit(1);
+    };
+
+    let parts = solution.select_parts(part).unwrap_or_else(|e| {
+        eprintln!("{e}.");
+        process::exit(1);
+    });
+
+    let max_duration = Duration::from_millis(max_millis);
+
+    for (i, (part, func)) in parts.into_iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{ANSI_BOLD}Day {day}, part {part}{ANSI_RESET}");
+
+        let sizes: Vec<usize> = sizes.clone().unwrap_or_else(|| {
+            (0..scale::MAX_STEPS)
+                .map(|step| scale::START_SIZE << step)
+                .collect()
+        });
+
+        let mut measurements: Vec<Measurement> = vec![];
+        for size in sizes {
+            if let Some(reason) = scale::over_budget(&measurements, size, max_duration) {
+                println!("Skipped size {size} and above, {reason}.");
+                break;
+            }
+
+            print!("{ANSI_ITALIC}size {size}...{ANSI_RESET}");
+            let _ = io::stdout().flush();
+
+            let measurement = scale::measure(func, generate, size);
+            print!("\r\x1b[2K");
+
+            match measurement {
+                Ok(measurement) => {
+                    let done = measurement.duration >= max_duration
+                        || measurement.generation >= max_duration;
+                    measurements.push(measurement);
+                    if done {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Size {size}: {e}");
+                    break;
+                }
+            }
+        }
+
+        print_measurements(&measurements);
+    }
+}
+
+fn print_measurements(measurements: &[Measurement]) {
+    let mut cells = vec![["Size", "Input", "Time", "Exponent"]
+        .map(String::from)
+        .to_vec()];
+
+    for (i, measurement) in measurements.iter().enumerate() {
+        let exponent = i
+            .checked_sub(1)
+            .and_then(|previous| scale::local_exponent(&measurements[previous], measurement))
+            .map_or_else(|| "-".into(), |exponent| format!("{exponent:.2}"));
+
+        let time = if measurement.samples == 1 {
+            format!("{:.1?}", measurement.duration)
+        } else {
+            format!(
+                "{:.1?} @ {} samples",
+                measurement.duration, measurement.samples
+            )
+        };
+
+        cells.push(vec![
+            measurement.size.to_string(),
+            format!("{} B", measurement.bytes),
+            time,
+            exponent,
+        ]);
+    }
+
+    for (i, line) in inputs::align_columns(&cells).iter().enumerate() {
+        if i == 0 {
+            println!("{ANSI_BOLD}{line}{ANSI_RESET}");
+        } else {
+            println!("{line}");
+        }
+    }
+
+    match scale::growth_exponent(measurements) {
+        Some((exponent, fitted)) => println!(
+            "Growth: {ANSI_BOLD}O(n^{exponent:.2}){ANSI_RESET}, fitted over sizes {}..={}.",
+            fitted[0].size,
+            fitted[fitted.len() - 1].size
+        ),
+        None => println!("Not enough measurements to fit a growth exponent."),
+    }
+}
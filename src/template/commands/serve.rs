@@ -0,0 +1,10 @@
+use std::process;
+
+use crate::template::server;
+
+pub fn handle(host: &str, port: u16) {
+    if let Err(e) = server::serve(&format!("{host}:{port}")) {
+        eprintln!("Failed to start server: {e}");
+        process::exit(1);
+    }
+}
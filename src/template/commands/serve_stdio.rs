@@ -0,0 +1,55 @@
+use std::io::{self, BufRead, Write};
+use std::process;
+
+use crate::template::rpc;
+
+pub fn handle() {
+    let mut output = match protocol_output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to set up stdout: {e}");
+            process::exit(1);
+        }
+    };
+
+    for line in io::stdin().lock().lines() {
+        let Ok(line) = line else {
+            break;
+        };
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if let Some(response) = rpc::handle_line(&line) {
+            if writeln!(output, "{response}")
+                .and_then(|()| output.flush())
+                .is_err()
+            {
+                break;
+            }
+        }
+    }
+}
+
+/// Solutions may print to stdout themselves, which would corrupt the protocol.
+/// Responses are written to a duplicate of stdout, while stdout itself is redirected to stderr.
+#[cfg(unix)]
+fn protocol_output() -> Result<std::fs::File, io::Error> {
+    use std::os::fd::{AsFd, AsRawFd};
+
+    let stdout = io::stdout();
+    let protocol = stdout.as_fd().try_clone_to_owned()?;
+
+    // SAFETY: both file descriptors are open for the lifetime of the process.
+    if unsafe { libc::dup2(io::stderr().as_raw_fd(), stdout.as_raw_fd()) } == -1 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(std::fs::File::from(protocol))
+}
+
+#[cfg(not(unix))]
+fn protocol_output() -> Result<io::Stdout, io::Error> {
+    Ok(io::stdout())
+}
@@ -0,0 +1,143 @@
+use std::cell::Cell;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process;
+use std::time::{Duration, Instant};
+
+use crate::template::registry;
+use crate::template::shrink::{self, Checker};
+use crate::template::{config, inputs, repl, Day, ANSI_BOLD, ANSI_RESET};
+
+pub use crate::template::shrink::RUN_COMMAND;
+
+/// Time a part may take on the original input before it counts as stuck.
+const MAX_RUN: Duration = Duration::from_secs(3600);
+
+pub fn handle(
+    day: Day,
+    part: Option<u8>,
+    input: Option<&str>,
+    against: Option<&str>,
+    timeout_millis: Option<u64>,
+    overwrite: bool,
+) {
+    let solution = registry::require(day).unwrap_or_else(|e| {
+        eprintln!("{e}.");
+        process::exit(1);
+    });
+
+    let output_path = config::get()
+        .data_folder("examples")
+        .join(format!("{day}-min.txt"));
+    if output_path.exists() && !overwrite {
+        eprintln!(
+            "\"{}\" exists already. Pass --overwrite to replace it.",
+            output_path.display()
+        );
+        process::exit(1);
+    }
+
+    let parts = solution.select_parts(part).unwrap_or_else(|e| {
+        eprintln!("{e}.");
+        process::exit(1);
+    });
+
+    let input_name = input.unwrap_or(inputs::DEFAULT_INPUT);
+    let input = repl::read_input(day, input_name).unwrap_or_else(|e| {
+        eprintln!("Failed to read input \"{input_name}\": {e}");
+        process::exit(1);
+    });
+
+    let mut errors = vec![];
+    let found = parts.into_iter().find_map(|(part, _)| {
+        let timer = Instant::now();
+        let behavior = match shrink::observe(day, part, &input, MAX_RUN) {
+            Ok(behavior) => behavior,
+            Err(e) => {
+                errors.push(e);
+                return None;
+            }
+        };
+        // shrunk inputs may send a solution into an endless loop, such runs are cut short.
+        let timeout = timeout_millis.map_or_else(
+            || (timer.elapsed() * 10).max(Duration::from_secs(1)),
+            Duration::from_millis,
+        );
+
+        match shrink::find_oracle(day, part, &input, behavior, against, timeout) {
+            Ok(oracle) => Some((part, Checker::new(day, part, oracle, timeout))),
+            Err(e) => {
+                errors.push(e);
+                None
+            }
+        }
+    });
+
+    let Some((part, mut checker)) = found else {
+        errors.dedup();
+        for e in errors {
+            eprintln!("{e}.");
+        }
+        eprintln!("Nothing to shrink.");
+        process::exit(1);
+    };
+
+    println!(
+        "{ANSI_BOLD}Day {day}, part {part}{ANSI_RESET} {} on input \"{input_name}\".",
+        checker.oracle().describe()
+    );
+
+    let started = Instant::now();
+    let checks = Cell::new(0);
+    let minimal = shrink::shrink(
+        &input,
+        |candidate| {
+            checks.set(checks.get() + 1);
+            print!("\r{} checks", checks.get());
+            let _ = io::stdout().flush();
+            checker.is_interesting(candidate)
+        },
+        |phase| {
+            println!(
+                "\rShrunk {}: {} → {}          ",
+                phase.name, phase.before, phase.after
+            );
+        },
+    );
+
+    if let Err(e) = fs::write(&output_path, &minimal) {
+        eprintln!("Failed to write \"{}\": {e}", output_path.display());
+        process::exit(1);
+    }
+
+    println!(
+        "Shrunk {} to {} byte(s) in {:.1?}, {} distinct input(s) checked.",
+        input.len(),
+        minimal.len(),
+        started.elapsed(),
+        checker.checks()
+    );
+    if minimal.lines().count() <= 20 {
+        println!("---\n{}\n---", minimal.trim_end());
+    }
+    println!(
+        "Wrote \"{}\". Read it in a test with `read_file_suffix(\"examples\", DAY, \"min\")`.",
+        output_path.display()
+    );
+}
+
+/// Runs a part on the input read from stdin and prints how it behaved, see [`shrink::observe`].
+pub fn run_part(day: Day, part: u8) {
+    let Some(func) = registry::get(day).and_then(|solution| solution.part(part)) else {
+        process::exit(1);
+    };
+
+    let mut input = String::new();
+    if io::stdin().read_to_string(&mut input).is_err() {
+        process::exit(1);
+    }
+
+    shrink::capture_panics();
+    let behavior = shrink::evaluate(func, &input);
+    println!("{}", shrink::encode(&behavior));
+}
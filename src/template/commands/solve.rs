@@ -1,9 +1,13 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{config, Day};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>, all_inputs: bool) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        config::get().bin_name(day),
+    ];
 
     if dhat {
         cmd_args.extend([
@@ -23,6 +27,10 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if all_inputs {
+        cmd_args.push("--all-inputs".to_string());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -0,0 +1,57 @@
+use crate::template::config;
+use crate::template::readme_benchmarks;
+use crate::template::server_time::{format_duration, format_server_time};
+use crate::template::solve_times::SolveTimes;
+use crate::template::timings::Timings;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+pub fn handle(update_readme: bool) {
+    let solve_times = SolveTimes::read_from_file();
+
+    if solve_times.data.is_empty() {
+        println!("No solve times recorded yet. Times are recorded when scaffolding or downloading a day and when submitting answers.");
+        return;
+    }
+
+    let year = config::get().year;
+    let duration = |seconds: Option<i64>| seconds.map_or_else(|| "-".into(), format_duration);
+
+    println!(
+        "{ANSI_BOLD}{:<5}{:<21}{:<13}{:<13}Since unlock{ANSI_RESET}",
+        "Day", "Started", "Part 1", "Part 2"
+    );
+
+    for time in &solve_times.data {
+        println!(
+            "{:<5}{:<21}{:<13}{:<13}{}",
+            time.day.to_string(),
+            time.started.map_or_else(|| "-".into(), format_server_time),
+            duration(time.time_to_part(1)),
+            duration(time.time_to_part(2)),
+            duration(year.and_then(|year| time.since_unlock(year))),
+        );
+    }
+
+    if year.is_none() {
+        println!();
+        println!("{ANSI_ITALIC}Set `year` in aoc.toml to show the time since unlock.{ANSI_RESET}");
+    }
+
+    if update_readme {
+        if !config::get().readme_solve_times {
+            println!("{ANSI_ITALIC}Set `readme = true` in the `[stats]` section of aoc.toml to add solve times to the readme.{ANSI_RESET}");
+            return;
+        }
+
+        let timings = Timings::read_from_file();
+        if timings.data.is_empty() {
+            println!("No benchmarks stored yet. Run `cargo time --store` first.");
+            return;
+        }
+
+        match readme_benchmarks::update(timings) {
+            Ok(()) => println!("Updated solve times in readme."),
+            Err(e) => eprintln!("Failed to update readme: {e:?}"),
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use std::process;
+
+use crate::template::answers::Answers;
+use crate::template::external;
+use crate::template::run_multi::child_commands;
+use crate::template::status::{self, DayStatus, PartStatus};
+use crate::template::timings::Timings;
+use crate::template::{
+    all_days, config, encryption, fingerprints, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+pub fn handle(record: bool, check: bool) {
+    let mut answers = Answers::read_from_file();
+
+    if record {
+        record_answers(&mut answers);
+    }
+
+    // recording answers for a changed input marks benchmarks as stale, read them afterwards.
+    let timings = Timings::read_from_file();
+
+    let mut statuses: Vec<DayStatus> = all_days()
+        .map(|day| DayStatus::collect(day, &timings, &answers))
+        .collect();
+
+    // tests need to compile and run every solution, so they only run on request.
+    if check {
+        let days: Vec<Day> = statuses.iter().map(|status| status.day).collect();
+        let tests = status::collect_test_results(&days);
+        for status in &mut statuses {
+            if let Some(results) = tests.get(&status.day) {
+                for (part, result) in status.parts.iter_mut().zip(results) {
+                    part.tests = *result;
+                }
+            }
+        }
+    }
+
+    print_calendar(&statuses);
+    println!();
+    print_details(&statuses);
+
+    let problems: Vec<String> = statuses.iter().flat_map(DayStatus::problems).collect();
+
+    if !problems.is_empty() {
+        println!();
+        for problem in &problems {
+            eprintln!("{problem}");
+        }
+
+        let unanswered = statuses
+            .iter()
+            .flat_map(|status| &status.parts)
+            .any(|part| part.is_solved() && !part.answer);
+        if unanswered {
+            eprintln!(
+                "Run `cargo status --record` to record the answers of your current solutions."
+            );
+        }
+        process::exit(1);
+    }
+}
+
+/// Records the current output of the solutions as answers of solved parts without an answer,
+/// e.g. of days that were solved before answers were recorded on submission.
+/// Fingerprints of the inputs are recorded as well, answers of a changed input are recorded again.
+fn record_answers(answers: &mut Answers) {
+    let config = config::get();
+    let timings = Timings::read_from_file();
+    let mut recorded = false;
+
+    for day in all_days() {
+        if let Ok(input) = encryption::read_to_string(&config.input_path(day)) {
+            if fingerprints::record(day, &input) && answers.mark_stale(day) {
+                recorded = true;
+            }
+        }
+
+        let status = DayStatus::collect(day, &timings, answers);
+        let parts: Vec<u8> = [1, 2]
+            .into_iter()
+            .filter(|&part| {
+                let stale = answers.is_stale(day, part);
+                let part = &status.parts[usize::from(part - 1)];
+                part.is_solved() && (!part.answer || stale)
+            })
+            .collect();
+
+        if parts.is_empty() {
+            continue;
+        }
+
+        println!("{ANSI_ITALIC}Running day {day}...{ANSI_RESET}");
+        let output = match child_commands::run_solution_streamed(day, false, true, |_| {}) {
+            Ok(output) => output,
+            Err(e) => {
+                eprintln!("Failed to run day {day}: {e:?}");
+                continue;
+            }
+        };
+
+        let (results, _) = external::parse_rust_output(&output);
+        for part in parts {
+            match &results[usize::from(part - 1)] {
+                Some(result) => {
+                    answers.set(day, part, result);
+                    recorded = true;
+                    println!("Recorded answer of day {day} part {part}: {result}");
+                }
+                None => eprintln!("Day {day} part {part} did not produce an answer."),
+            }
+        }
+    }
+
+    if recorded {
+        match answers.store_file() {
+            Ok(()) => println!("Stored answers."),
+            Err(e) => eprintln!("Failed to store answers: {e}"),
+        }
+        println!();
+    }
+}
+
+fn print_calendar(statuses: &[DayStatus]) {
+    for week in statuses.chunks(5) {
+        let row: Vec<String> = week
+            .iter()
+            .map(|status| {
+                let stars: String = status.parts.iter().map(PartStatus::star).collect();
+                if status.scaffolded {
+                    format!("{ANSI_BOLD}{}{ANSI_RESET} {stars}", status.day)
+                } else {
+                    format!("{ANSI_ITALIC}{}{ANSI_RESET} {stars}", status.day)
+                }
+            })
+            .collect();
+        println!("{}", row.join("   "));
+    }
+
+    println!();
+    println!("★ answered & benched   ☆ solved   · open");
+}
+
+fn print_details(statuses: &[DayStatus]) {
+    println!("{ANSI_BOLD}Day  Input  Example  Tests  Answer  Bench{ANSI_RESET}");
+
+    for status in statuses.iter().filter(|s| s.scaffolded) {
+        let [part_1, part_2] = &status.parts;
+        println!(
+            "{}   {}      {}        {} {}    {} {}     {} {}",
+            status.day,
+            check(status.input),
+            check(status.example),
+            test(part_1.tests),
+            test(part_2.tests),
+            check(part_1.answer),
+            check(part_2.answer),
+            check(part_1.benched),
+            check(part_2.benched),
+        );
+    }
+}
+
+fn check(value: bool) -> char {
+    if value {
+        '✔'
+    } else {
+        '✖'
+    }
+}
+
+fn test(result: Option<bool>) -> char {
+    result.map_or('-', check)
+}
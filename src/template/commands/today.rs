@@ -0,0 +1,71 @@
+use std::io::{stdout, Write};
+use std::process;
+
+use chrono::DateTime;
+
+use crate::template::commands::{download, read, scaffold};
+use crate::template::unlock::{self, Clock, SystemClock};
+use crate::template::{config, Day};
+
+pub fn handle(wait: bool, fake_now: Option<&str>) {
+    let mut clock = match fake_now {
+        Some(value) => match DateTime::parse_from_rfc3339(value) {
+            Ok(start) => SystemClock::starting_at(start.to_utc()),
+            Err(e) => {
+                eprintln!("Invalid value for `--fake-now`, expected an RFC 3339 timestamp: {e}");
+                process::exit(1);
+            }
+        },
+        None => SystemClock::default(),
+    };
+
+    let day = match unlock::day_at(clock.now()) {
+        // when waiting, skip days that were already scaffolded and wait for the next one instead.
+        Some(day) if !wait || !config::get().bin_path(day).exists() => day,
+        _ if wait => wait_for_next_unlock(&mut clock),
+        _ => {
+            eprintln!(
+                "`today` command can only be run between the 1st and \
+                the 25th of december. Please use `scaffold` with a specific day \
+                or pass `--wait` to wait for the next puzzle."
+            );
+            process::exit(1)
+        }
+    };
+
+    // download first, so scaffolding can extract examples from the puzzle.
+    download::handle(day);
+    scaffold::handle(day, false, None);
+    read::handle(day, None, false);
+}
+
+fn wait_for_next_unlock(clock: &mut impl Clock) -> Day {
+    let Some((year, day, unlock_time)) = unlock::next_unlock(clock.now()) else {
+        eprintln!("Could not determine the next unlock time.");
+        process::exit(1);
+    };
+
+    // waiting for a puzzle of another year would scaffold its day number into the configured year.
+    if let Some(configured) = config::get().year {
+        if i32::from(configured) != year {
+            eprintln!(
+                "The next puzzle unlocks in {year}, but `year` is set to {configured}. \
+                Run `cargo new-year {year}` to start the new year first."
+            );
+            process::exit(1);
+        }
+    }
+
+    let mut stdout = stdout();
+
+    unlock::wait_until(clock, unlock_time, |remaining| {
+        print!(
+            "\r⏳ Day {day} unlocks in {}   ",
+            unlock::format_countdown(remaining)
+        );
+        let _ = stdout.flush();
+    });
+
+    println!("\r🔓 Day {day} is unlocked!                    ");
+    day
+}
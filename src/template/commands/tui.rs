@@ -0,0 +1,10 @@
+use std::process;
+
+use crate::template::tui;
+
+pub fn handle() {
+    if let Err(e) = tui::run() {
+        eprintln!("Failed to run dashboard: {e}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,617 @@
+/// Module that loads the project configuration from `aoc.toml`.
+/// The file lives in the project root, which is located by walking up from the working directory,
+/// so commands can be run from any subdirectory. All relative paths are resolved against that root.
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::OnceLock,
+};
+
+use crate::template::Day;
+
+pub static CONFIG_FILE_NAME: &str = "aoc.toml";
+
+/// Directory containing the solution binaries. Cargo only discovers binaries in `src/bin`,
+/// and `build.rs` registers the solutions from there.
+pub static SOLUTIONS_DIR: &str = "src/bin";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Settings that can be set in the config file, besides the commands in `[external.NN]` tables.
+const KEYS: &[&str] = &[
+    "year",
+    "bin_prefix",
+    "paths.data",
+    "paths.inputs",
+    "paths.examples",
+    "paths.puzzles",
+    "paths.timings",
+    "paths.answers",
+    "paths.solve_times",
+    "paths.fingerprints",
+    "paths.readme",
+    "paths.chart",
+    "paths.solutions",
+    "scaffold.template",
+    "scaffold.templates",
+    "aoc_cli.session_file",
+    "bench.target_millis",
+    "bench.min_samples",
+    "bench.max_samples",
+    "stats.readme",
+    "encryption.key_file",
+];
+
+/// Settings for `cargo time` and `--time` runs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Approximate total time to spend benching a part.
+    pub target_millis: u64,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+/// A solution of a day outside of this project, e.g. in another language.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExternalCommand {
+    pub day: Day,
+    /// Name of the solution in tables, e.g. `python`.
+    pub name: String,
+    /// Program and arguments, split at whitespace outside of quotes.
+    pub command: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// Absolute path of the project root.
+    pub root: PathBuf,
+    /// Puzzle year, falls back to the `AOC_YEAR` environment variable.
+    pub year: Option<u16>,
+    /// Prefix of the cargo binary names of solutions. Archived years use e.g. `2024-`.
+    pub bin_prefix: String,
+    /// Directory containing puzzle inputs, examples and descriptions unless overridden.
+    pub data_dir: String,
+    pub inputs_dir: String,
+    pub examples_dir: String,
+    pub puzzles_dir: String,
+    pub timings_file: String,
+    pub answers_file: String,
+    pub solve_times_file: String,
+    pub fingerprints_file: String,
+    pub readme_file: String,
+    /// Bar chart of the stored benchmarks, embedded below the readme benchmark table.
+    pub chart_file: String,
+    /// Template used by `scaffold`. If the file does not exist, the built-in template is used.
+    pub template: String,
+    /// Directory containing named templates that can be selected with `scaffold --template <name>`.
+    pub templates_dir: String,
+    /// Session cookie file passed to aoc-cli. If not set, aoc-cli uses its default location.
+    pub session_file: Option<String>,
+    pub bench: BenchConfig,
+    /// Whether the readme benchmark table includes a solve time column.
+    pub readme_solve_times: bool,
+    /// File containing the key for encrypted inputs and puzzle descriptions. Must not be committed.
+    pub key_file: String,
+    /// External solutions that are compared with the Rust solutions, ordered by day and name.
+    pub external: Vec<ExternalCommand>,
+}
+
+impl Config {
+    /// Builds the configuration for `root` from the contents of a config file.
+    pub fn parse(root: PathBuf, contents: &str) -> Result<Self, String> {
+        let values = parse_toml(contents)?;
+
+        let mut unknown: Vec<&String> = values
+            .keys()
+            .filter(|key| !KEYS.contains(&key.as_str()) && !key.starts_with("external."))
+            .collect();
+        unknown.sort();
+        if let Some(key) = unknown.first() {
+            return Err(format!("unknown setting `{key}`."));
+        }
+
+        let string = |key: &str| -> Result<Option<String>, String> {
+            match values.get(key) {
+                None => Ok(None),
+                Some(Value::String(s)) => Ok(Some(s.clone())),
+                Some(_) => Err(format!("expected `{key}` to be a string.")),
+            }
+        };
+
+        let integer = |key: &str| -> Result<Option<i64>, String> {
+            match values.get(key) {
+                None => Ok(None),
+                Some(Value::Integer(i)) => Ok(Some(*i)),
+                Some(_) => Err(format!("expected `{key}` to be an integer.")),
+            }
+        };
+
+        let boolean = |key: &str| -> Result<Option<bool>, String> {
+            match values.get(key) {
+                None => Ok(None),
+                Some(Value::Boolean(b)) => Ok(Some(*b)),
+                Some(_) => Err(format!("expected `{key}` to be a boolean.")),
+            }
+        };
+
+        let unsigned = |key: &str| -> Result<Option<u64>, String> {
+            integer(key)?
+                .map(|i| u64::try_from(i).map_err(|_| format!("expected `{key}` to be positive.")))
+                .transpose()
+        };
+
+        let year = match integer("year")? {
+            Some(year) => {
+                Some(u16::try_from(year).map_err(|_| "expected `year` to be a valid year.")?)
+            }
+            None => env::var("AOC_YEAR").ok().and_then(|y| y.parse().ok()),
+        };
+
+        let data_dir = string("paths.data")?.unwrap_or_else(|| "data".into());
+        let in_data = |name: &str| format!("{data_dir}/{name}");
+
+        let bench = BenchConfig {
+            target_millis: unsigned("bench.target_millis")?.unwrap_or(1000),
+            min_samples: unsigned("bench.min_samples")?.map_or(10, u128::from),
+            max_samples: unsigned("bench.max_samples")?.map_or(10000, u128::from),
+        };
+
+        if bench.min_samples == 0 || bench.min_samples > bench.max_samples {
+            return Err(
+                "expected `bench.min_samples` to be positive and not above `bench.max_samples`."
+                    .into(),
+            );
+        }
+
+        if let Some(solutions) = string("paths.solutions")? {
+            if solutions.trim_start_matches("./").trim_end_matches('/') != SOLUTIONS_DIR {
+                return Err(format!(
+                    "expected `paths.solutions` to be \"{SOLUTIONS_DIR}\", cargo only finds solution binaries there."
+                ));
+            }
+        }
+
+        let external = parse_external(&values)?;
+
+        Ok(Config {
+            root,
+            year,
+            bin_prefix: string("bin_prefix")?.unwrap_or_default(),
+            inputs_dir: string("paths.inputs")?.unwrap_or_else(|| in_data("inputs")),
+            examples_dir: string("paths.examples")?.unwrap_or_else(|| in_data("examples")),
+            puzzles_dir: string("paths.puzzles")?.unwrap_or_else(|| in_data("puzzles")),
+            timings_file: string("paths.timings")?.unwrap_or_else(|| in_data("timings.json")),
+            answers_file: string("paths.answers")?.unwrap_or_else(|| in_data("answers.json")),
+            solve_times_file: string("paths.solve_times")?
+                .unwrap_or_else(|| in_data("solve_times.json")),
+            fingerprints_file: string("paths.fingerprints")?
+                .unwrap_or_else(|| in_data("fingerprints.json")),
+            readme_file: string("paths.readme")?.unwrap_or_else(|| "README.md".into()),
+            chart_file: string("paths.chart")?.unwrap_or_else(|| ".assets/benchmarks.svg".into()),
+            template: string("scaffold.template")?.unwrap_or_else(|| "src/template.txt".into()),
+            templates_dir: string("scaffold.templates")?.unwrap_or_else(|| "templates".into()),
+            session_file: string("aoc_cli.session_file")?,
+            readme_solve_times: boolean("stats.readme")?.unwrap_or(false),
+            key_file: string("encryption.key_file")?.unwrap_or_else(|| ".aoc-key".into()),
+            data_dir,
+            bench,
+            external,
+        })
+    }
+
+    /// Resolves a path from the config against the project root.
+    pub fn path(&self, relative: &str) -> PathBuf {
+        match relative.strip_prefix("~/") {
+            Some(home_relative) => env::var_os("HOME")
+                .map_or_else(|| PathBuf::from(relative), PathBuf::from)
+                .join(home_relative),
+            None => self.root.join(relative),
+        }
+    }
+
+    /// Resolves a data folder such as `inputs` or `examples`.
+    pub fn data_folder(&self, folder: &str) -> PathBuf {
+        match folder {
+            "inputs" => self.path(&self.inputs_dir),
+            "examples" => self.path(&self.examples_dir),
+            "puzzles" => self.path(&self.puzzles_dir),
+            folder => self.path(&self.data_dir).join(folder),
+        }
+    }
+
+    pub fn input_path(&self, day: Day) -> PathBuf {
+        self.data_folder("inputs").join(format!("{day}.txt"))
+    }
+
+    pub fn example_path(&self, day: Day) -> PathBuf {
+        self.data_folder("examples").join(format!("{day}.txt"))
+    }
+
+    pub fn puzzle_path(&self, day: Day) -> PathBuf {
+        self.data_folder("puzzles").join(format!("{day}.md"))
+    }
+
+    /// Path of the solution binary for a day.
+    pub fn bin_path(&self, day: Day) -> PathBuf {
+        self.path(SOLUTIONS_DIR).join(format!("{day}.rs"))
+    }
+
+    /// Name of the cargo binary of a day, as passed to `cargo run --bin`.
+    pub fn bin_name(&self, day: Day) -> String {
+        format!("{}{day}", self.bin_prefix)
+    }
+
+    /// External solutions registered for a day.
+    pub fn external_commands(&self, day: Day) -> impl Iterator<Item = &ExternalCommand> {
+        self.external
+            .iter()
+            .filter(move |external| external.day == day)
+    }
+
+    /// Link to the solution binary for a day, relative to the project root.
+    pub fn bin_link(&self, day: Day) -> String {
+        format!("./{SOLUTIONS_DIR}/{day}.rs")
+    }
+}
+
+/// Loads the configuration of the current project on first use.
+/// An invalid config file is read again on the next call, so long-running commands can recover once it is fixed.
+pub fn load() -> Result<&'static Config, String> {
+    if let Some(config) = CONFIG.get() {
+        return Ok(config);
+    }
+
+    let root = find_root();
+    let contents = fs::read_to_string(root.join(CONFIG_FILE_NAME)).unwrap_or_default();
+    let config = Config::parse(root, &contents)
+        .map_err(|e| format!("Failed to parse {CONFIG_FILE_NAME}: {e}"))?;
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+/// Returns the configuration of the current project.
+/// Panics if the config file is invalid, commands check it up front with [`load`].
+pub fn get() -> &'static Config {
+    load().unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Walks up from the working directory to the first directory containing `aoc.toml`.
+/// Falls back to the crate manifest directory if there is none.
+fn find_root() -> PathBuf {
+    let cwd = env::current_dir().unwrap_or_default();
+    find_root_from(&cwd).unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+}
+
+fn find_root_from(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| dir.join(CONFIG_FILE_NAME).is_file())
+        .map(Path::to_path_buf)
+}
+
+/// Collects the external solutions from `[external.NN]` tables, where every key names a command, e.g.
+/// `python = "python3 day05.py"`.
+fn parse_external(values: &HashMap<String, Value>) -> Result<Vec<ExternalCommand>, String> {
+    let mut external = vec![];
+
+    for (key, value) in values {
+        let Some(table_key) = key.strip_prefix("external.") else {
+            continue;
+        };
+
+        let day = table_key
+            .split_once('.')
+            .and_then(|(day, _)| Day::from_str(day).ok())
+            .ok_or_else(|| format!("expected `{key}` to be in an `[external.<day>]` table."))?;
+
+        let command = match value {
+            Value::String(command) => split_command(command)
+                .ok_or_else(|| format!("expected quotes in `{key}` to be closed."))?,
+            _ => vec![],
+        };
+
+        if command.is_empty() {
+            return Err(format!("expected `{key}` to be a command."));
+        }
+
+        external.push(ExternalCommand {
+            day,
+            name: key.rsplit('.').next().unwrap_or_default().to_string(),
+            command,
+        });
+    }
+
+    external.sort_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+    Ok(external)
+}
+
+/// Splits a command into program and arguments at whitespace. Single or double quotes group
+/// arguments that contain whitespace, e.g. `python3 'my day.py'`. Returns `None` for unclosed quotes.
+fn split_command(command: &str) -> Option<Vec<String>> {
+    let mut args = vec![];
+    let mut arg: Option<String> = None;
+    let mut quote = None;
+
+    for c in command.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => arg.get_or_insert_with(String::new).push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                arg.get_or_insert_with(String::new);
+            }
+            (None, c) if c.is_whitespace() => args.extend(arg.take()),
+            (None, c) => arg.get_or_insert_with(String::new).push(c),
+        }
+    }
+
+    if quote.is_some() {
+        return None;
+    }
+
+    args.extend(arg);
+    Some(args)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Value {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+}
+
+/// Parses the subset of TOML used by the config file: tables, comments and
+/// string, integer or boolean values. Keys are flattened to `table.key`.
+fn parse_toml(contents: &str) -> Result<HashMap<String, Value>, String> {
+    let mut values = HashMap::new();
+    let mut table = String::new();
+
+    for (i, line) in contents.lines().enumerate() {
+        let error = |message: &str| format!("line {}: {message}", i + 1);
+        let line = strip_comment(line).trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[') {
+            table = name
+                .strip_suffix(']')
+                .ok_or_else(|| error("expected `]` after table name."))?
+                .trim()
+                .to_string();
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error("expected `key = value`."))?;
+
+        let key = if table.is_empty() {
+            key.trim().to_string()
+        } else {
+            format!("{table}.{}", key.trim())
+        };
+
+        let value = parse_value(value.trim()).ok_or_else(|| error("unsupported value."))?;
+
+        if values.insert(key, value).is_some() {
+            return Err(error("duplicate key."));
+        }
+    }
+
+    Ok(values)
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            '\\' if in_string => escaped = !escaped,
+            '"' if !escaped => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => escaped = false,
+        }
+        if c != '\\' {
+            escaped = false;
+        }
+    }
+
+    line
+}
+
+fn parse_value(value: &str) -> Option<Value> {
+    if let Some(s) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        let mut unescaped = String::with_capacity(s.len());
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                match chars.next()? {
+                    'n' => unescaped.push('\n'),
+                    't' => unescaped.push('\t'),
+                    c @ ('"' | '\\') => unescaped.push(c),
+                    _ => return None,
+                }
+            } else {
+                unescaped.push(c);
+            }
+        }
+        return Some(Value::String(unescaped));
+    }
+
+    match value {
+        "true" => Some(Value::Boolean(true)),
+        "false" => Some(Value::Boolean(false)),
+        v => v.replace('_', "").parse().ok().map(Value::Integer),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{find_root_from, parse_toml, Config, Value};
+    use crate::day;
+
+    #[test]
+    fn parses_toml_subset() {
+        let values = parse_toml(
+            r#"
+            # comment
+            year = 2_024 # trailing comment
+
+            [paths]
+            inputs = "my # inputs"
+            escaped = "a \"quoted\" \\ path"
+
+            [bench]
+            enabled = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(values.get("year"), Some(&Value::Integer(2024)));
+        assert_eq!(
+            values.get("paths.inputs"),
+            Some(&Value::String("my # inputs".into()))
+        );
+        assert_eq!(
+            values.get("paths.escaped"),
+            Some(&Value::String("a \"quoted\" \\ path".into()))
+        );
+        assert_eq!(values.get("bench.enabled"), Some(&Value::Boolean(true)));
+    }
+
+    #[test]
+    fn rejects_invalid_toml() {
+        assert!(parse_toml("[paths").is_err());
+        assert!(parse_toml("year").is_err());
+        assert!(parse_toml("year = [2024]").is_err());
+        assert!(parse_toml("a = 1\na = 2").is_err());
+    }
+
+    #[test]
+    fn uses_defaults() {
+        let config = Config::parse(PathBuf::from("/aoc"), "year = 2023").unwrap();
+        assert_eq!(config.year, Some(2023));
+        assert_eq!(
+            config.input_path(day!(1)),
+            PathBuf::from("/aoc/data/inputs/01.txt")
+        );
+        assert_eq!(
+            config.data_folder("timings"),
+            PathBuf::from("/aoc/data/timings")
+        );
+        assert_eq!(
+            config.bin_path(day!(7)),
+            PathBuf::from("/aoc/src/bin/07.rs")
+        );
+        assert_eq!(config.bin_link(day!(7)), "./src/bin/07.rs");
+        assert_eq!(config.bin_name(day!(7)), "07");
+        assert_eq!(config.bench.min_samples, 10);
+        assert_eq!(config.session_file, None);
+        assert!(!config.readme_solve_times);
+        assert_eq!(
+            config.path(&config.chart_file),
+            PathBuf::from("/aoc/.assets/benchmarks.svg")
+        );
+        assert_eq!(
+            config.path(&config.key_file),
+            PathBuf::from("/aoc/.aoc-key")
+        );
+    }
+
+    #[test]
+    fn overrides_paths() {
+        let config = Config::parse(
+            PathBuf::from("/aoc"),
+            r#"
+            bin_prefix = "2023-"
+
+            [paths]
+            data = "puzzle-data"
+            examples = "tests/examples"
+            solutions = "./src/bin/"
+            chart = "docs/benchmarks.svg"
+
+            [bench]
+            max_samples = 100
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.input_path(day!(2)),
+            PathBuf::from("/aoc/puzzle-data/inputs/02.txt")
+        );
+        assert_eq!(
+            config.example_path(day!(2)),
+            PathBuf::from("/aoc/tests/examples/02.txt")
+        );
+        assert_eq!(config.bin_link(day!(2)), "./src/bin/02.rs");
+        assert_eq!(config.chart_file, "docs/benchmarks.svg");
+        assert_eq!(config.bench.max_samples, 100);
+        assert_eq!(config.bin_name(day!(2)), "2023-02");
+    }
+
+    #[test]
+    fn parses_external_commands() {
+        let config = Config::parse(
+            PathBuf::from("/aoc"),
+            r#"
+            [external.05]
+            python = "python3  day05.py"
+            go = "go run ./go/05"
+            ruby = "ruby 'day 05.rb' \"\""
+
+            [external.1]
+            node = "node day01.js"
+            "#,
+        )
+        .unwrap();
+
+        let names: Vec<_> = config.external.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["node", "go", "python", "ruby"]);
+        let commands: Vec<_> = config
+            .external_commands(day!(5))
+            .map(|e| e.command.clone())
+            .collect();
+        assert_eq!(commands[1], vec!["python3", "day05.py"]);
+        assert_eq!(commands[2], vec!["ruby", "day 05.rb", ""]);
+        assert_eq!(config.external_commands(day!(2)).count(), 0);
+
+        assert!(Config::parse(PathBuf::new(), "[external]\npython = \"python3\"").is_err());
+        assert!(Config::parse(PathBuf::new(), "[external.26]\npython = \"python3\"").is_err());
+        assert!(Config::parse(PathBuf::new(), "[external.05]\npython = \" \"").is_err());
+        assert!(Config::parse(PathBuf::new(), "[external.05]\npython = \"python3 'a\"").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        assert!(Config::parse(PathBuf::new(), "year = \"2024\"").is_err());
+        assert!(Config::parse(PathBuf::new(), "year = 70000").is_err());
+        assert!(Config::parse(PathBuf::new(), "[bench]\nmin_samples = 0").is_err());
+        assert!(Config::parse(PathBuf::new(), "[paths]\ninputs = 1").is_err());
+        assert!(Config::parse(PathBuf::new(), "[paths]\nsolutions = \"solutions\"").is_err());
+        assert_eq!(
+            Config::parse(PathBuf::new(), "[bench]\ntarget_milis = 500"),
+            Err("unknown setting `bench.target_milis`.".into())
+        );
+        assert!(Config::parse(PathBuf::new(), "[paths]\ninput = \"in\"").is_err());
+        assert!(Config::parse(PathBuf::new(), "[stats]\nreadme = 1").is_err());
+    }
+
+    #[test]
+    fn finds_root_from_subdirectory() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        assert_eq!(
+            find_root_from(&root.join("src").join("template")),
+            Some(root)
+        );
+    }
+}
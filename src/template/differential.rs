@@ -0,0 +1,291 @@
+/// Module that compares solutions with slow reference implementations on generated inputs.
+/// A day registers its reference implementations and an input generator with [`differential!`](crate::differential),
+/// which adds a test per part to the tests of the day. Generators registered with [`generator!`](crate::generator)
+/// are also used by `cargo scale`. Every test case is generated from a seed,
+/// so a failing case can be replayed from the seed in its report.
+///
+/// Environment variables:
+///  - `AOC_CASES`: number of test cases per part, defaults to 100.
+///  - `AOC_SEED`: runs the single test case with this seed.
+use std::env;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+use crate::template::registry;
+use crate::template::{config, Day};
+
+/// Generates an input from a random number generator and a size. The meaning of the size is up to the generator,
+/// e.g. the number of lines. Small sizes should produce small inputs.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// Test cases cycle through the sizes `1..=MAX_SIZE`.
+pub const MAX_SIZE: usize = 16;
+
+const DEFAULT_CASES: u64 = 100;
+
+/// A small, seeded pseudo-random number generator (SplitMix64) for input generators.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in the range, e.g. `rng.range(1..=9)`.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "range is empty");
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index into a slice of length `len`.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "cannot pick from nothing");
+        #[allow(clippy::cast_possible_truncation)]
+        let index = (self.next_u64() % len as u64) as usize;
+        index
+    }
+
+    /// Returns `true` with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        #[allow(clippy::cast_precision_loss)]
+        let sample = (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64;
+        sample < probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// Size of the input generated for a seed.
+pub fn size_for(seed: u64) -> usize {
+    #[allow(clippy::cast_possible_truncation)]
+    let offset = (seed % MAX_SIZE as u64) as usize;
+    1 + offset
+}
+
+/// A generated input on which a part and its reference differ.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub solution: String,
+    pub reference: String,
+}
+
+/// Runs a part and its reference on the input generated for a seed. Panics count as results.
+pub fn run_case<T: PartialEq + Debug>(
+    seed: u64,
+    solution: impl Fn(&str) -> T,
+    reference: impl Fn(&str) -> T,
+    generate: Generator,
+) -> Option<Failure> {
+    let size = size_for(seed);
+    let input = generate(&mut Rng::new(seed), size);
+
+    let solution = registry::catch_panic(|| solution(&input));
+    let reference = registry::catch_panic(|| reference(&input));
+
+    if matches!((&solution, &reference), (Ok(a), Ok(b)) if a == b) {
+        return None;
+    }
+
+    let describe = |result: Result<T, String>| match result {
+        Ok(answer) => format!("{answer:?}"),
+        Err(message) => format!("panicked: {message}"),
+    };
+
+    Some(Failure {
+        seed,
+        size,
+        input,
+        solution: describe(solution),
+        reference: describe(reference),
+    })
+}
+
+/// Compares a part with its reference implementation on generated inputs.
+/// Panics with a report of the smallest failing input if any case differs.
+pub fn check<T: PartialEq + Debug>(
+    day: Day,
+    part: &str,
+    solution: impl Fn(&str) -> T,
+    reference: impl Fn(&str) -> T,
+    generate: Generator,
+) {
+    let seeds = match env_number("AOC_SEED") {
+        Some(seed) => seed..seed + 1,
+        None => 0..env_number("AOC_CASES").unwrap_or(DEFAULT_CASES),
+    };
+    let cases = seeds.end - seeds.start;
+
+    let failures: Vec<Failure> = seeds
+        .filter_map(|seed| run_case(seed, &solution, &reference, generate))
+        .collect();
+
+    if let Some(failure) = failures.iter().min_by_key(|failure| failure.input.len()) {
+        panic!(
+            "{}",
+            report(day, part, failure, failures.len(), cases).join("\n")
+        );
+    }
+}
+
+fn report(day: Day, part: &str, failure: &Failure, failures: usize, cases: u64) -> Vec<String> {
+    vec![
+        format!(
+            "{part} differs from its reference on {failures} of {cases} case(s). Smallest case: seed {}, size {}.",
+            failure.seed, failure.size
+        ),
+        format!("  solution:  {}", failure.solution),
+        format!("  reference: {}", failure.reference),
+        "input:".into(),
+        failure.input.trim_end().into(),
+        format!(
+            "Replay it with `AOC_SEED={} cargo test --bin {} differential_tests::{part}`.",
+            failure.seed,
+            config::get().bin_name(day)
+        ),
+    ]
+}
+
+fn env_number(name: &str) -> Option<u64> {
+    let value = env::var(name).ok()?;
+    match value.trim().parse() {
+        Ok(number) => Some(number),
+        Err(_) => panic!("expected `{name}` to be a number, got \"{value}\""),
+    }
+}
+
+/// Registers slow reference implementations of the parts of a day along with an input generator.
+/// Adds a test per part to the tests of the day that compares the part with its reference on generated inputs.
+///
+/// ```ignore
+/// advent_of_code::differential! {
+///     generate,
+///     part_one: part_one_reference,
+///     part_two: part_two_reference,
+/// }
+/// ```
+#[macro_export]
+macro_rules! differential {
+    ($generate:ident, $( $part:ident: $reference:ident ),+ $(,)?) => {
+        #[cfg(test)]
+        mod differential_tests {
+            $(
+                #[test]
+                fn $part() {
+                    $crate::template::differential::check(
+                        super::DAY,
+                        stringify!($part),
+                        super::$part,
+                        super::$reference,
+                        super::$generate,
+                    );
+                }
+            )+
+        }
+    };
+}
+
+/// Registers a generator of inputs of a requested size in the solution registry, e.g. a grid side or a number of lines.
+/// `cargo scale` benches the parts of the day on generated inputs of growing size. Generated inputs must be valid
+/// puzzle inputs, a part that does not finish on them stalls the measurement.
+///
+/// ```ignore
+/// advent_of_code::generator!(generate);
+/// ```
+#[macro_export]
+macro_rules! generator {
+    ($generate:ident) => {
+        /// Generator of inputs of this day in the solution registry.
+        #[allow(dead_code)]
+        pub const GENERATOR: Option<$crate::template::differential::Generator> = Some($generate);
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{run_case, size_for, Rng, MAX_SIZE};
+
+    fn digits(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| char::from(b'0' + u8::try_from(rng.range(0..=9)).unwrap()))
+            .collect()
+    }
+
+    fn digit_sum(input: &str) -> u32 {
+        input.chars().filter_map(|c| c.to_digit(10)).sum()
+    }
+
+    #[test]
+    fn generates_reproducibly() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        assert_eq!(
+            (0..10).map(|_| a.next_u64()).collect::<Vec<_>>(),
+            (0..10).map(|_| b.next_u64()).collect::<Vec<_>>()
+        );
+
+        let mut rng = Rng::new(1);
+        assert!((0..1000)
+            .map(|_| rng.range(3..=5))
+            .all(|n| (3..=5).contains(&n)));
+        assert_eq!(rng.range(4..=4), 4);
+        let _ = rng.range(0..=u64::MAX);
+
+        let mut items: Vec<u32> = (0..20).collect();
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+
+        assert_eq!(size_for(0), 1);
+        assert_eq!(size_for(MAX_SIZE as u64 - 1), MAX_SIZE);
+    }
+
+    #[test]
+    fn compares_with_reference() {
+        assert_eq!(run_case(3, digit_sum, digit_sum, digits), None);
+
+        // an "optimized" digit sum that ignores nines.
+        let wrong = |input: &str| {
+            input
+                .chars()
+                .filter(|&c| c != '9')
+                .map(|c| c.to_digit(10).unwrap())
+                .sum()
+        };
+        let failure = (0..100)
+            .find_map(|seed| run_case(seed, wrong, digit_sum, digits))
+            .unwrap();
+        assert!(failure.input.contains('9'));
+        assert_eq!(failure.input.len(), failure.size);
+        assert_ne!(failure.solution, failure.reference);
+
+        let panics = |_: &str| -> u32 { panic!("boom") };
+        let failure = run_case(0, panics, digit_sum, digits).unwrap();
+        assert_eq!(failure.solution, "panicked: boom");
+    }
+}
@@ -0,0 +1,259 @@
+/// Module that encrypts puzzle inputs and descriptions so they can be committed without publishing them.
+/// Encrypted files live next to their plaintext with an additional `.enc` extension and are read
+/// transparently if the plaintext is missing. The key is read from a local, uncommitted file.
+/// Without the `encryption` feature, files are read as they are.
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "encryption")]
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+#[cfg(feature = "encryption")]
+use crate::template::config;
+
+/// Prefix of encrypted files, followed by the nonce and the ciphertext.
+#[cfg(feature = "encryption")]
+const MAGIC: &[u8] = b"AOCENC1\n";
+#[cfg(feature = "encryption")]
+const NONCE_LEN: usize = 12;
+pub const EXTENSION: &str = "enc";
+
+#[derive(Debug)]
+pub enum EncryptionError {
+    #[cfg(feature = "encryption")]
+    MissingKey(PathBuf),
+    #[cfg(feature = "encryption")]
+    InvalidKey,
+    #[cfg(feature = "encryption")]
+    InvalidFile,
+    #[cfg(feature = "encryption")]
+    DecryptionFailed,
+    IO(io::Error),
+}
+
+impl Display for EncryptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(feature = "encryption")]
+            EncryptionError::MissingKey(path) => write!(
+                f,
+                "no encryption key found at \"{}\". Ask a teammate for the key or run `cargo encrypt` to create one.",
+                path.display()
+            ),
+            #[cfg(feature = "encryption")]
+            EncryptionError::InvalidKey => {
+                write!(f, "the encryption key must be 64 hexadecimal characters.")
+            }
+            #[cfg(feature = "encryption")]
+            EncryptionError::InvalidFile => write!(f, "the file is not an encrypted file."),
+            #[cfg(feature = "encryption")]
+            EncryptionError::DecryptionFailed => write!(
+                f,
+                "the file could not be decrypted. It was either encrypted with a different key or modified."
+            ),
+            EncryptionError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for EncryptionError {
+    fn from(e: io::Error) -> Self {
+        EncryptionError::IO(e)
+    }
+}
+
+/// Path of the encrypted counterpart of a file, e.g. `01.txt.enc`.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(EXTENSION);
+    PathBuf::from(name)
+}
+
+#[cfg(feature = "encryption")]
+pub fn key_path() -> PathBuf {
+    let config = config::get();
+    config.path(&config.key_file)
+}
+
+#[cfg(feature = "encryption")]
+/// Parses a key from its hexadecimal representation.
+pub fn parse_key(hex: &str) -> Result<Key, EncryptionError> {
+    let hex = hex.trim();
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err(EncryptionError::InvalidKey);
+    }
+
+    let bytes = (0..32)
+        .map(|i| u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|_| EncryptionError::InvalidKey)?;
+
+    Ok(*Key::from_slice(&bytes))
+}
+
+#[cfg(feature = "encryption")]
+pub fn format_key(key: &Key) -> String {
+    key.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(feature = "encryption")]
+pub fn generate_key() -> Key {
+    ChaCha20Poly1305::generate_key(&mut OsRng)
+}
+
+#[cfg(feature = "encryption")]
+/// Reads the key from the configured key file, creating a new key if none exists yet.
+pub fn read_or_create_key() -> Result<Key, EncryptionError> {
+    match read_key() {
+        Err(EncryptionError::MissingKey(path)) => {
+            let key = generate_key();
+            fs::write(&path, format_key(&key) + "\n")?;
+            println!("Created new encryption key \"{}\"", path.display());
+            Ok(key)
+        }
+        result => result,
+    }
+}
+
+#[cfg(feature = "encryption")]
+/// Reads the key from the configured key file.
+pub fn read_key() -> Result<Key, EncryptionError> {
+    let path = key_path();
+    match fs::read_to_string(&path) {
+        Ok(hex) => parse_key(&hex),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(EncryptionError::MissingKey(path)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(feature = "encryption")]
+pub fn encrypt(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = ChaCha20Poly1305::new(key)
+        .encrypt(&nonce, plaintext)
+        .expect("encryption does not fail for in-memory buffers");
+
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+#[cfg(feature = "encryption")]
+pub fn decrypt(key: &Key, data: &[u8]) -> Result<Vec<u8>, EncryptionError> {
+    let data = data
+        .strip_prefix(MAGIC)
+        .filter(|data| data.len() >= NONCE_LEN)
+        .ok_or(EncryptionError::InvalidFile)?;
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+    ChaCha20Poly1305::new(key)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| EncryptionError::DecryptionFailed)
+}
+
+#[cfg(feature = "encryption")]
+/// Folders with files that may not be published, along with the extension of their plaintext files.
+pub fn protected_folders() -> [(PathBuf, &'static str); 2] {
+    let config = config::get();
+    [
+        (config.data_folder("inputs"), "txt"),
+        (config.data_folder("puzzles"), "md"),
+    ]
+}
+
+#[cfg(feature = "encryption")]
+/// Lists the files in `folder` and its subfolders with the given extension, sorted by path.
+/// Subfolders hold the named inputs of a day, e.g. `data/inputs/01/alice.txt`.
+pub fn files_with_extension(folder: &Path, extension: &str) -> Result<Vec<PathBuf>, io::Error> {
+    let mut files = vec![];
+
+    for entry in fs::read_dir(folder)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(files_with_extension(&path, extension)?);
+        } else if path.extension().is_some_and(|ext| ext == extension) {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Reads a file, decrypting its `.enc` counterpart if the plaintext does not exist.
+#[cfg(feature = "encryption")]
+pub fn read_to_string(path: &Path) -> Result<String, EncryptionError> {
+    match fs::read_to_string(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let encrypted = match fs::read(encrypted_path(path)) {
+                Ok(encrypted) => encrypted,
+                // report the missing plaintext rather than the missing encrypted file.
+                Err(_) => return Err(e.into()),
+            };
+            let plaintext = decrypt(&read_key()?, &encrypted)?;
+            String::from_utf8(plaintext).map_err(|_| EncryptionError::InvalidFile)
+        }
+        result => Ok(result?),
+    }
+}
+
+/// Reads a file. Encrypted counterparts are only decrypted with the `encryption` feature.
+#[cfg(not(feature = "encryption"))]
+pub fn read_to_string(path: &Path) -> Result<String, EncryptionError> {
+    Ok(fs::read_to_string(path)?)
+}
+
+#[cfg(all(feature = "test_lib", feature = "encryption"))]
+mod tests {
+    use super::{decrypt, encrypt, encrypted_path, format_key, generate_key, parse_key};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn round_trips() {
+        let key = generate_key();
+        let encrypted = encrypt(&key, b"1 2 3\n");
+        assert!(!encrypted.windows(5).any(|w| w == b"1 2 3"));
+        assert_eq!(decrypt(&key, &encrypted).unwrap(), b"1 2 3\n");
+    }
+
+    #[test]
+    fn uses_fresh_nonces() {
+        let key = generate_key();
+        assert_ne!(encrypt(&key, b"input"), encrypt(&key, b"input"));
+    }
+
+    #[test]
+    fn rejects_wrong_key_and_tampering() {
+        let key = generate_key();
+        let mut encrypted = encrypt(&key, b"input");
+
+        assert!(decrypt(&generate_key(), &encrypted).is_err());
+
+        let last = encrypted.len() - 1;
+        encrypted[last] ^= 1;
+        assert!(decrypt(&key, &encrypted).is_err());
+
+        assert!(decrypt(&key, b"plain text").is_err());
+    }
+
+    #[test]
+    fn parses_keys() {
+        let key = generate_key();
+        assert_eq!(parse_key(&format!("{}\n", format_key(&key))).unwrap(), key);
+        assert!(parse_key("abc").is_err());
+        assert!(parse_key(&"g".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn appends_extension() {
+        assert_eq!(
+            encrypted_path(Path::new("data/inputs/01.txt")),
+            PathBuf::from("data/inputs/01.txt.enc")
+        );
+    }
+}
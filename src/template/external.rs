@@ -0,0 +1,305 @@
+/// Module that runs external solutions of a day, e.g. in other languages, and compares their answers with the Rust solution.
+/// External solutions are registered in the `[external.NN]` tables of the config file. They run in the project root,
+/// receive the input of the day on stdin and its path in `AOC_INPUT`, and print the answer of part one on the first line
+/// and the answer of part two on the second.
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::template::config::{self, ExternalCommand};
+use crate::template::run_multi::child_commands::parse_nanos;
+use crate::template::{encryption, inputs, Day, ANSI_BOLD, ANSI_RESET};
+
+/// Answers of a solution on the input of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Run {
+    pub name: String,
+    pub answers: [Option<String>; 2],
+    pub duration: Option<Duration>,
+    /// Set if the solution could not be started or failed.
+    pub error: Option<String>,
+}
+
+/// Runs the external solutions of a day and prints their answers next to the answers of the Rust solution,
+/// given as the output of its binary. Returns `false` if any answers disagree or a solution could not be run.
+pub fn compare(day: Day, rust_output: &[String]) -> bool {
+    let path = config::get().input_path(day);
+    let input = match encryption::read_to_string(&path) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input for external solutions: {e}");
+            return false;
+        }
+    };
+
+    let mut runs = vec![];
+    if !rust_output.is_empty() {
+        let (answers, duration) = parse_rust_output(rust_output);
+        runs.push(Run {
+            name: "rust".into(),
+            answers,
+            duration,
+            error: None,
+        });
+    }
+    runs.extend(
+        config::get()
+            .external_commands(day)
+            .map(|external| run(external, &path, &input)),
+    );
+
+    println!();
+    for (i, line) in format_table(&runs).iter().enumerate() {
+        if i == 0 {
+            println!("{ANSI_BOLD}{line}{ANSI_RESET}");
+        } else {
+            println!("{line}");
+        }
+    }
+
+    for run in &runs {
+        if let Some(error) = &run.error {
+            eprintln!("{}: {error}", run.name);
+        }
+    }
+
+    let agree = disagreements(&runs).iter().all(|part| !part);
+    if !agree {
+        eprintln!("The solutions of day {day} disagree.");
+    }
+    agree
+}
+
+/// Runs an external solution once and times it, including the startup of its runtime.
+pub fn run(external: &ExternalCommand, input_path: &Path, input: &str) -> Run {
+    let timer = Instant::now();
+    let result = execute(external, input_path, input);
+    let duration = timer.elapsed();
+
+    match result {
+        Ok(stdout) => Run {
+            name: external.name.clone(),
+            answers: parse_output(&stdout),
+            duration: Some(duration),
+            error: None,
+        },
+        Err(error) => Run {
+            name: external.name.clone(),
+            answers: [None, None],
+            duration: None,
+            error: Some(error),
+        },
+    }
+}
+
+fn execute(external: &ExternalCommand, input_path: &Path, input: &str) -> Result<String, String> {
+    let (program, args) = external.command.split_first().ok_or("command is empty")?;
+
+    let mut child = Command::new(program)
+        .args(args)
+        .current_dir(&config::get().root)
+        .env("AOC_DAY", external.day.to_string())
+        .env("AOC_INPUT", input_path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("could not start `{program}`: {e}"))?;
+
+    // the input is written on a separate thread, so a solution that does not read stdin cannot block.
+    let mut stdin = child.stdin.take().ok_or("stdin is not piped")?;
+    let input = input.to_string();
+    let writer = thread::spawn(move || {
+        let _ = stdin.write_all(input.as_bytes());
+    });
+
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    let _ = writer.join();
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let message = stderr.lines().rev().find(|line| !line.trim().is_empty());
+        return Err(match message {
+            Some(message) => format!("{} ({})", message.trim(), output.status),
+            None => output.status.to_string(),
+        });
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parses the answers of an external solution from the first two non-empty lines of its output.
+/// A `Part 1:` or `Part 2:` prefix is stripped.
+pub fn parse_output(stdout: &str) -> [Option<String>; 2] {
+    let mut lines = stdout
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+    [1, 2].map(|part| {
+        lines.next().map(|line| {
+            line.strip_prefix(&format!("Part {part}:"))
+                .unwrap_or(line)
+                .trim()
+                .to_string()
+        })
+    })
+}
+
+/// Parses the answers and the total duration of the parts from the output of a solution binary,
+/// e.g. `Part 1: 42 (1.2ms)`. Multi-line answers are not compared.
+pub fn parse_rust_output(output: &[String]) -> ([Option<String>; 2], Option<Duration>) {
+    let mut answers = [None, None];
+    let mut nanos = None;
+
+    for line in output {
+        // intermediate results are overwritten with a carriage return.
+        let line = line.rsplit('\r').next().unwrap_or_default();
+
+        for (part, answer) in answers.iter_mut().enumerate() {
+            let Some(rest) = line.strip_prefix(&format!("Part {}: ", part + 1)) else {
+                continue;
+            };
+
+            if let Some((result, timing)) = rest
+                .strip_prefix(ANSI_BOLD)
+                .and_then(|rest| rest.split_once(ANSI_RESET))
+            {
+                *answer = Some(result.to_string());
+
+                let timing = timing.trim().trim_start_matches('(').trim_end_matches(')');
+                if let Some(part_nanos) =
+                    timing.split('@').next().and_then(|t| parse_nanos(t.trim()))
+                {
+                    *nanos.get_or_insert(0.0) += part_nanos;
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = nanos.map(|nanos: f64| Duration::from_nanos(nanos as u64));
+    (answers, duration)
+}
+
+/// Whether the answers to each part disagree. Missing answers are ignored, a failed solution disagrees on every part.
+fn disagreements(runs: &[Run]) -> [bool; 2] {
+    let failed = runs.iter().any(|run| run.error.is_some());
+    [0, 1].map(|part| {
+        let mut answers = runs.iter().filter_map(|run| run.answers[part].as_ref());
+        failed
+            || answers
+                .next()
+                .is_some_and(|first| answers.any(|answer| answer != first))
+    })
+}
+
+/// Formats the answers of every solution as a table. Answers that differ from the first solution with an answer,
+/// the Rust solution if it has one, are flagged.
+pub fn format_table(runs: &[Run]) -> Vec<String> {
+    let reference: [Option<&String>; 2] =
+        [0, 1].map(|part| runs.iter().find_map(|run| run.answers[part].as_ref()));
+
+    let mut cells = vec![["Solution", "Part 1", "Part 2", "Time"]
+        .map(String::from)
+        .to_vec()];
+    cells.extend(runs.iter().map(|run| {
+        let mut row = vec![run.name.clone()];
+        row.extend(
+            [0, 1].map(|part| match (&run.answers[part], reference[part]) {
+                (Some(answer), Some(reference)) if answer != reference => format!("{answer} ✖"),
+                (Some(answer), _) => answer.clone(),
+                (None, _) => "-".into(),
+            }),
+        );
+        row.push(
+            run.duration
+                .map_or_else(|| "-".into(), |d| format!("{d:.1?}")),
+        );
+        row
+    }));
+
+    inputs::align_columns(&cells)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{disagreements, format_table, parse_output, parse_rust_output, Run};
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    fn run(name: &str, answers: [Option<&str>; 2], millis: u64) -> Run {
+        Run {
+            name: name.into(),
+            answers: answers.map(|answer| answer.map(String::from)),
+            duration: Some(Duration::from_millis(millis)),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn parses_external_output() {
+        assert_eq!(
+            parse_output("11\n\n31\ntrailing\n"),
+            [Some("11".into()), Some("31".into())]
+        );
+        assert_eq!(
+            parse_output("Part 1: 11\nPart 2: 31"),
+            [Some("11".into()), Some("31".into())]
+        );
+        assert_eq!(parse_output(" 11 \n"), [Some("11".into()), None]);
+        assert_eq!(parse_output(""), [None, None]);
+    }
+
+    #[test]
+    fn parses_rust_output() {
+        let output = vec![
+            format!("Part 1: {ANSI_BOLD}11{ANSI_RESET}\rPart 1: {ANSI_BOLD}11{ANSI_RESET} (1.5ms)"),
+            format!(
+                "Part 2: {ANSI_BOLD}31{ANSI_RESET} > {ANSI_ITALIC}benching{ANSI_RESET}\rPart 2: {ANSI_BOLD}31{ANSI_RESET} (500.0µs @ 2000 samples)"
+            ),
+        ];
+        assert_eq!(
+            parse_rust_output(&output),
+            (
+                [Some("11".into()), Some("31".into())],
+                Some(Duration::from_millis(2))
+            )
+        );
+
+        let unsolved = vec!["Part 1: ✖\rPart 1: ✖             ".to_string()];
+        assert_eq!(parse_rust_output(&unsolved), ([None, None], None));
+    }
+
+    #[test]
+    fn flags_disagreements() {
+        let runs = vec![
+            run("rust", [Some("11"), None], 2),
+            run("python", [Some("11"), Some("31")], 40),
+            run("go", [Some("12"), Some("31")], 5),
+        ];
+
+        assert_eq!(disagreements(&runs), [true, false]);
+        assert_eq!(
+            format_table(&runs),
+            vec![
+                "Solution  Part 1  Part 2  Time",
+                "rust      11      -       2.0ms",
+                "python    11      31      40.0ms",
+                "go        12 ✖    31      5.0ms",
+            ]
+        );
+        assert_eq!(disagreements(&runs[..2]), [false, false]);
+    }
+
+    #[test]
+    fn flags_failed_solutions() {
+        let mut failed = run("ruby", [None, None], 0);
+        failed.error = Some("could not start `ruby`".into());
+
+        let runs = vec![run("rust", [Some("11"), Some("31")], 2), failed];
+        assert_eq!(disagreements(&runs), [true, true]);
+    }
+}
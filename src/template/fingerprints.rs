@@ -0,0 +1,379 @@
+/// Module that records a fingerprint of each input to detect empty, misplaced or changed input files.
+/// Fingerprints are recorded along with answers. If the input of a day changed by then, stored answers
+/// and benchmarks of that day are marked as stale.
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::timings::Timings;
+use crate::template::{config, Day};
+
+fn file_path() -> PathBuf {
+    let config = config::get();
+    config.path(&config.fingerprints_file)
+}
+
+/// Summary of an input file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fingerprint {
+    pub day: Day,
+    /// 64 bit FNV-1a hash of the input, as hexadecimal.
+    pub hash: String,
+    pub lines: usize,
+    /// Length of the longest line.
+    pub width: usize,
+}
+
+impl Fingerprint {
+    pub fn of(day: Day, input: &str) -> Self {
+        Fingerprint {
+            day,
+            hash: hash(input),
+            lines: input.lines().count(),
+            width: input.lines().map(|l| l.chars().count()).max().unwrap_or(0),
+        }
+    }
+
+    /// Describes the shape of the input, e.g. `140 line(s) of up to 140 characters`.
+    pub fn describe(&self) -> String {
+        format!("{} line(s) of up to {} characters", self.lines, self.width)
+    }
+}
+
+/// FNV-1a is stable across platforms and rust versions, unlike the hasher of the standard library.
+fn hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// Represents the fingerprints of a set of inputs.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Fingerprints {
+    pub data: Vec<Fingerprint>,
+}
+
+impl Fingerprints {
+    /// Dehydrate fingerprints to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(file_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate fingerprints from a JSON file. If not present, returns empty fingerprints.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(file_path())
+            .map_err(|x| x.to_string())
+            .and_then(Fingerprints::try_from)
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, day: Day) -> Option<&Fingerprint> {
+        self.data.iter().find(|f| f.day == day)
+    }
+
+    /// Stores the fingerprint of a day, returns the previous one.
+    pub fn replace(&mut self, fingerprint: Fingerprint) -> Option<Fingerprint> {
+        match self.data.iter_mut().find(|f| f.day == fingerprint.day) {
+            Some(existing) => Some(std::mem::replace(existing, fingerprint)),
+            None => {
+                self.data.push(fingerprint);
+                self.data.sort_unstable_by_key(|f| f.day);
+                None
+            }
+        }
+    }
+}
+
+/// Problems with an input that are reported before running a solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Warning {
+    Empty,
+    MatchesExample(String),
+    Changed {
+        previous: Fingerprint,
+        current: Fingerprint,
+    },
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::Empty => write!(
+                f,
+                "the input file is empty. Run `cargo download <day>` or paste your input."
+            ),
+            Warning::MatchesExample(name) => write!(
+                f,
+                "the input file is identical to the example \"{name}\". Did you paste the example into the input file?"
+            ),
+            Warning::Changed { previous, current } => write!(
+                f,
+                "the input file changed since answers were recorded ({} -> {}). Run `cargo status --record` to record answers for the new input.",
+                previous.describe(),
+                current.describe()
+            ),
+        }
+    }
+}
+
+/// Compares an input against the examples of its day and its previous fingerprint.
+pub fn check(
+    input: &str,
+    current: &Fingerprint,
+    examples: &[(String, String)],
+    previous: Option<&Fingerprint>,
+) -> Option<Warning> {
+    if input.trim().is_empty() {
+        return Some(Warning::Empty);
+    }
+
+    if let Some((name, _)) = examples
+        .iter()
+        .find(|(_, example)| example.trim() == input.trim())
+    {
+        return Some(Warning::MatchesExample(name.clone()));
+    }
+
+    previous
+        .filter(|previous| previous.hash != current.hash)
+        .map(|previous| Warning::Changed {
+            previous: previous.clone(),
+            current: current.clone(),
+        })
+}
+
+/// Reads the example files of a day, e.g. `01.txt` and `01-2.txt`, along with their file names.
+fn read_examples(day: Day) -> Vec<(String, String)> {
+    let folder = config::get().data_folder("examples");
+    let prefix = day.to_string();
+
+    let Ok(entries) = fs::read_dir(folder) else {
+        return vec![];
+    };
+
+    let mut examples: Vec<(String, String)> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let name = entry.file_name().into_string().ok()?;
+            let stem = name.strip_suffix(".txt")?;
+            if stem != prefix && !stem.starts_with(&format!("{prefix}-")) {
+                return None;
+            }
+            Some((name, fs::read_to_string(entry.path()).ok()?))
+        })
+        .collect();
+
+    examples.sort();
+    examples
+}
+
+/// Checks the input of a day before it is run and prints warnings. Does not write any files.
+pub fn check_input(day: Day, input: &str) {
+    let current = Fingerprint::of(day, input);
+    let fingerprints = Fingerprints::read_from_file();
+
+    if let Some(warning) = check(input, &current, &read_examples(day), fingerprints.get(day)) {
+        eprintln!("Warning: {warning}");
+    }
+}
+
+/// Records the fingerprint of the input that answers of a day are recorded for.
+/// Returns whether the input changed since the previous recording, stored benchmarks of the day are marked
+/// as stale in that case. Callers mark the answers they hold as stale before recording new ones.
+pub fn record(day: Day, input: &str) -> bool {
+    let current = Fingerprint::of(day, input);
+    let mut fingerprints = Fingerprints::read_from_file();
+
+    match check(input, &current, &read_examples(day), fingerprints.get(day)) {
+        // do not record inputs that are likely wrong.
+        Some(Warning::Empty | Warning::MatchesExample(_)) => return false,
+        // the fingerprint is known already.
+        None if fingerprints.get(day).is_some() => return false,
+        _ => {}
+    }
+
+    let previous = fingerprints.replace(current);
+    if let Err(e) = fingerprints.store_file() {
+        eprintln!("Failed to store input fingerprint: {e}");
+    }
+
+    let changed = previous.is_some();
+    if changed {
+        let mut timings = Timings::read_from_file();
+        if timings.mark_stale(day) {
+            if let Err(e) = timings.store_file() {
+                eprintln!("Failed to store timings: {e}");
+            }
+        }
+    }
+    changed
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Fingerprints> for JsonValue {
+    fn from(value: Fingerprints) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Fingerprints {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Fingerprints {
+            data: json_data
+                .iter()
+                .map(Fingerprint::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&Fingerprint> for JsonValue {
+    fn from(value: &Fingerprint) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("hash".into(), JsonValue::String(value.hash.clone()));
+        map.insert("lines".into(), JsonValue::Number(value.lines as f64));
+        map.insert("width".into(), JsonValue::Number(value.width as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+impl TryFrom<&JsonValue> for Fingerprint {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected fingerprint to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected fingerprint.day to be a Day struct.")?;
+
+        let hash = json
+            .get("hash")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected fingerprint.hash to be a string.")?;
+
+        let number = |key: &str| -> Result<usize, String> {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|n| *n as usize)
+                .ok_or_else(|| format!("Expected fingerprint.{key} to be a number."))
+        };
+
+        Ok(Fingerprint {
+            day,
+            hash: hash.clone(),
+            lines: number("lines")?,
+            width: number("width")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, Fingerprint, Fingerprints, Warning};
+    use crate::day;
+
+    #[test]
+    fn fingerprints_inputs() {
+        let fingerprint = Fingerprint::of(day!(1), "abc\nde\n");
+        assert_eq!(fingerprint.lines, 2);
+        assert_eq!(fingerprint.width, 3);
+        assert_eq!(fingerprint.hash, Fingerprint::of(day!(1), "abc\nde\n").hash);
+        assert_ne!(fingerprint.hash, Fingerprint::of(day!(1), "abc\ndf\n").hash);
+        // FNV-1a of the empty string is its offset basis.
+        assert_eq!(Fingerprint::of(day!(1), "").hash, "cbf29ce484222325");
+    }
+
+    #[test]
+    fn warns_about_empty_inputs() {
+        let current = Fingerprint::of(day!(1), "\n");
+        assert_eq!(check("\n", &current, &[], None), Some(Warning::Empty));
+    }
+
+    #[test]
+    fn warns_about_pasted_examples() {
+        let examples = vec![
+            ("01.txt".to_string(), "1 2\n".to_string()),
+            ("01-2.txt".to_string(), "3 4\n".to_string()),
+        ];
+        let current = Fingerprint::of(day!(1), "3 4");
+        assert_eq!(
+            check("3 4", &current, &examples, None),
+            Some(Warning::MatchesExample("01-2.txt".into()))
+        );
+    }
+
+    #[test]
+    fn warns_about_changed_inputs() {
+        let previous = Fingerprint::of(day!(1), "1 2\n3 4\n");
+        let current = Fingerprint::of(day!(1), "5 6\n");
+
+        assert!(check("1 2\n3 4\n", &previous, &[], Some(&previous)).is_none());
+        assert!(check("5 6\n", &current, &[], None).is_none());
+        assert_eq!(
+            check("5 6\n", &current, &[], Some(&previous)),
+            Some(Warning::Changed {
+                previous: previous.clone(),
+                current: current.clone(),
+            })
+        );
+    }
+
+    #[test]
+    fn serializes_round_trip() {
+        let mut fingerprints = Fingerprints::default();
+        assert!(fingerprints
+            .replace(Fingerprint::of(day!(2), "a\nbb\n"))
+            .is_none());
+        assert!(fingerprints
+            .replace(Fingerprint::of(day!(1), "a"))
+            .is_none());
+        assert!(fingerprints
+            .replace(Fingerprint::of(day!(2), "ccc"))
+            .is_some());
+
+        let json = tinyjson::JsonValue::from(fingerprints.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Fingerprints::try_from(json).unwrap();
+        assert_eq!(parsed.data, fingerprints.data);
+        assert_eq!(parsed.data[0].day, day!(1));
+        assert_eq!(parsed.get(day!(2)), Some(&Fingerprint::of(day!(2), "ccc")));
+    }
+}
@@ -0,0 +1,161 @@
+/// Module that renders stored timings into a single, self-contained HTML page.
+/// Styles, scripts and charts are inlined so the file can be published as-is.
+use std::{fmt::Write, fs, io, path::Path};
+
+use crate::template::config;
+use crate::template::svg_chart::{self, Scale};
+use crate::template::timings::{Timing, Timings};
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2rem auto; max-width: 760px; color: #1f2328; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 2rem; }
+th, td { border-bottom: 1px solid #d0d7de; padding: 0.35rem 0.6rem; text-align: left; }
+th { cursor: pointer; user-select: none; }
+th[data-dir=asc]::after { content: ' ▲'; }
+th[data-dir=desc]::after { content: ' ▼'; }
+td.num { font-family: monospace; text-align: right; }
+";
+
+const SCRIPT: &str = "
+document.querySelectorAll('th[data-col]').forEach((th) => {
+  th.addEventListener('click', () => {
+    const body = th.closest('table').tBodies[0];
+    const col = Number(th.dataset.col);
+    const asc = th.dataset.dir !== 'asc';
+    th.parentElement.querySelectorAll('th').forEach((other) => delete other.dataset.dir);
+    th.dataset.dir = asc ? 'asc' : 'desc';
+    Array.from(body.rows)
+      .sort((a, b) => {
+        const diff = Number(a.cells[col].dataset.sort) - Number(b.cells[col].dataset.sort);
+        return asc ? diff : -diff;
+      })
+      .forEach((row) => body.appendChild(row));
+  });
+});
+";
+
+/// Renders the report for the given timings and writes it to `path`.
+pub fn write(timings: &Timings, path: &Path) -> Result<(), io::Error> {
+    fs::write(path, render(timings))
+}
+
+fn render(timings: &Timings) -> String {
+    let mut html = String::new();
+
+    let total_millis = timings.total_millis();
+    let completed = timings
+        .data
+        .iter()
+        .filter(|t| timings.is_day_complete(t.day))
+        .count();
+
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<title>Advent of Code benchmarks</title>\n");
+    let _ = writeln!(html, "<style>{STYLE}</style>");
+    html.push_str("</head>\n<body>\n<h1>Advent of Code benchmarks</h1>\n");
+    let _ = writeln!(
+        html,
+        "<p><strong>{completed}</strong> of 25 days fully benched. <strong>Total: {total_millis:.2}ms</strong></p>"
+    );
+
+    html.push_str("<table>\n<thead>\n<tr>");
+    for (col, name) in ["Day", "Part 1", "Part 2", "Total", "Status"]
+        .iter()
+        .enumerate()
+    {
+        let _ = write!(html, "<th data-col=\"{col}\">{name}</th>");
+    }
+    html.push_str("</tr>\n</thead>\n<tbody>\n");
+    for timing in &timings.data {
+        html.push_str(&render_row(timing, timings.is_day_complete(timing.day)));
+    }
+    html.push_str("</tbody>\n</table>\n");
+
+    html.push_str("<h2>Cost per part</h2>\n");
+    html.push_str(&svg_chart::bar_chart(timings, Scale::Linear));
+
+    let _ = writeln!(html, "<script>{SCRIPT}</script>");
+    html.push_str("</body>\n</html>\n");
+
+    html
+}
+
+fn render_row(timing: &Timing, is_complete: bool) -> String {
+    let part_cell = |part: u8, label: &Option<String>| {
+        format!(
+            "<td class=\"num\" data-sort=\"{}\">{}</td>",
+            timing.part_nanos(part).unwrap_or(-1.0),
+            escape(label.as_deref().unwrap_or("-"))
+        )
+    };
+
+    let (status, status_rank) = if is_complete {
+        ("complete", 2)
+    } else {
+        ("partial", 1)
+    };
+
+    format!(
+        "<tr><td data-sort=\"{}\"><a href=\"{}\">Day {}</a></td>{}{}<td class=\"num\" data-sort=\"{}\">{:.2}ms</td><td data-sort=\"{status_rank}\">{status}</td></tr>\n",
+        timing.day.into_inner(),
+        escape(&config::get().bin_link(timing.day)),
+        timing.day.into_inner(),
+        part_cell(1, &timing.part_1),
+        part_cell(2, &timing.part_2),
+        timing.total_nanos,
+        timing.total_nanos / 1_000_000_f64,
+    )
+}
+
+/// Escapes text for use in HTML content and attribute values.
+pub fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{escape, render};
+    use crate::{
+        day,
+        template::{config, timings::get_mock_timings},
+    };
+
+    #[test]
+    fn renders_self_contained_document() {
+        let html = render(&get_mock_timings());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<svg"));
+        assert!(html.contains("<script>"));
+        assert!(!html.contains("src=\"http"));
+        assert!(!html.contains("<link"));
+    }
+
+    #[test]
+    fn renders_rows_with_sort_keys_and_links() {
+        let html = render(&get_mock_timings());
+        let link = format!("<a href=\"{}\">Day 1</a>", config::get().bin_link(day!(1)));
+        assert!(html.contains(&link));
+        assert!(html.contains("<td class=\"num\" data-sort=\"10000000\">10ms</td>"));
+        assert!(html.contains("<td class=\"num\" data-sort=\"40000000\">40ms</td>"));
+        assert!(html.contains("<td class=\"num\" data-sort=\"-1\">-</td>"));
+        assert!(html.contains("<td data-sort=\"1\">partial</td>"));
+    }
+
+    #[test]
+    fn renders_summary() {
+        let html = render(&get_mock_timings());
+        assert!(html.contains("<strong>2</strong> of 25 days fully benched."));
+        assert!(html.contains("Total: 140.00ms"));
+    }
+
+    #[test]
+    fn escapes_html() {
+        assert_eq!(
+            escape("<a href=\"x\">&</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+    }
+}
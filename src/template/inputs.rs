@@ -0,0 +1,237 @@
+/// Module that discovers all inputs of a day. Besides the default input `NN.txt`, the folder `NN/` may hold
+/// named inputs of other accounts, e.g. `NN/alice.txt`, with their expected answers in `NN/alice.answers`.
+/// An answer file holds the answer of part one on the first line and the answer of part two on the second.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::answers::Answers;
+use crate::template::encryption::{self, encrypted_path};
+use crate::template::{config, Day};
+
+/// Name of the default input in tables.
+pub const DEFAULT_INPUT: &str = "default";
+
+/// An input of a day along with the answers it is known to produce.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedInput {
+    pub name: String,
+    pub path: PathBuf,
+    pub expected: [Option<String>; 2],
+}
+
+impl NamedInput {
+    pub fn read(&self) -> Result<String, encryption::EncryptionError> {
+        encryption::read_to_string(&self.path)
+    }
+}
+
+/// Folder containing the named inputs of a day, e.g. `data/inputs/01/`.
+pub fn folder(day: Day) -> PathBuf {
+    config::get().data_folder("inputs").join(day.to_string())
+}
+
+/// Lists the default input, if present, followed by the named inputs of a day sorted by name.
+/// The expected answers of the default input are the accepted answers from the answers file.
+pub fn list(day: Day) -> Vec<NamedInput> {
+    let mut inputs = vec![];
+
+    let default_path = config::get().input_path(day);
+    if has_content(&default_path) || encrypted_path(&default_path).exists() {
+        let answers = Answers::read_from_file();
+        inputs.push(NamedInput {
+            name: DEFAULT_INPUT.into(),
+            path: default_path,
+            expected: [1, 2].map(|part| answers.get(day, part).map(String::from)),
+        });
+    }
+
+    let folder = folder(day);
+    let mut names: Vec<String> = fs::read_dir(&folder)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let name = entry.ok()?.file_name().into_string().ok()?;
+                    let name = name
+                        .strip_suffix(&format!(".{}", encryption::EXTENSION))
+                        .unwrap_or(&name);
+                    name.strip_suffix(".txt").map(String::from)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    names.sort();
+    names.dedup();
+
+    inputs.extend(names.into_iter().map(|name| {
+        let expected = fs::read_to_string(folder.join(format!("{name}.answers")))
+            .map(|contents| parse_answers(&contents))
+            .unwrap_or_default();
+
+        NamedInput {
+            path: folder.join(format!("{name}.txt")),
+            name,
+            expected,
+        }
+    }));
+
+    inputs
+}
+
+fn has_content(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+/// Parses an answer file. Empty lines and `-` mark unknown answers.
+pub fn parse_answers(contents: &str) -> [Option<String>; 2] {
+    let mut lines = contents.lines().map(str::trim);
+    [lines.next(), lines.next()].map(|line| {
+        line.filter(|answer| !answer.is_empty() && *answer != "-")
+            .map(String::from)
+    })
+}
+
+/// Result of a part on a single input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The answer matches the expected answer.
+    Correct(String),
+    /// The answer differs from the expected answer, or no answer was produced.
+    Wrong(Option<String>, String),
+    /// No expected answer is known.
+    Unchecked(Option<String>),
+}
+
+impl Outcome {
+    pub fn new(answer: Option<String>, expected: Option<&String>) -> Self {
+        match (answer, expected) {
+            (Some(answer), Some(expected)) if answer == *expected => Outcome::Correct(answer),
+            (answer, Some(expected)) => Outcome::Wrong(answer, expected.clone()),
+            (answer, None) => Outcome::Unchecked(answer),
+        }
+    }
+
+    fn cell(&self) -> String {
+        match self {
+            Outcome::Correct(answer) => format!("{answer} ✔"),
+            Outcome::Wrong(answer, expected) => format!(
+                "{} ✖ (expected {expected})",
+                answer.as_deref().unwrap_or("-")
+            ),
+            Outcome::Unchecked(answer) => answer.clone().unwrap_or_else(|| "-".into()),
+        }
+    }
+}
+
+/// Formats the outcomes of every input as a table with a column per part.
+pub fn format_table(parts: &[u8], rows: &[(String, Vec<Outcome>)]) -> Vec<String> {
+    let mut cells: Vec<Vec<String>> = vec![];
+    cells.push(
+        std::iter::once("Input".to_string())
+            .chain(parts.iter().map(|part| format!("Part {part}")))
+            .collect(),
+    );
+    cells.extend(rows.iter().map(|(name, outcomes)| {
+        std::iter::once(name.clone())
+            .chain(outcomes.iter().map(Outcome::cell))
+            .collect()
+    }));
+
+    align_columns(&cells)
+}
+
+/// Pads the cells of a table so that its columns line up.
+pub fn align_columns(cells: &[Vec<String>]) -> Vec<String> {
+    let columns = cells.iter().map(Vec::len).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|i| {
+            cells
+                .iter()
+                .filter_map(|row| row.get(i))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    cells
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_table, parse_answers, Outcome};
+
+    #[test]
+    fn parses_answer_files() {
+        assert_eq!(
+            parse_answers("42\n1337\n"),
+            [Some("42".into()), Some("1337".into())]
+        );
+        assert_eq!(parse_answers("-\n7"), [None, Some("7".into())]);
+        assert_eq!(parse_answers(" 42 "), [Some("42".into()), None]);
+        assert_eq!(parse_answers(""), [None, None]);
+    }
+
+    #[test]
+    fn compares_answers() {
+        let expected = "11".to_string();
+        assert_eq!(
+            Outcome::new(Some("11".into()), Some(&expected)),
+            Outcome::Correct("11".into())
+        );
+        assert_eq!(
+            Outcome::new(Some("12".into()), Some(&expected)),
+            Outcome::Wrong(Some("12".into()), "11".into())
+        );
+        assert_eq!(
+            Outcome::new(None, Some(&expected)),
+            Outcome::Wrong(None, "11".into())
+        );
+        assert_eq!(
+            Outcome::new(Some("12".into()), None),
+            Outcome::Unchecked(Some("12".into()))
+        );
+    }
+
+    #[test]
+    fn formats_tables() {
+        let rows = vec![
+            (
+                "default".to_string(),
+                vec![
+                    Outcome::Correct("11".into()),
+                    Outcome::Unchecked(Some("31".into())),
+                ],
+            ),
+            (
+                "bob".to_string(),
+                vec![
+                    Outcome::Wrong(Some("12".into()), "13".into()),
+                    Outcome::Unchecked(None),
+                ],
+            ),
+        ];
+
+        assert_eq!(
+            format_table(&[1, 2], &rows),
+            vec![
+                "Input    Part 1              Part 2",
+                "default  11 ✔                31",
+                "bob      12 ✖ (expected 13)  -",
+            ]
+        );
+    }
+}
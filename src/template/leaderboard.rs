@@ -0,0 +1,338 @@
+/// Module that parses private leaderboards in the JSON format served by Advent of Code
+/// (`/{year}/leaderboard/private/view/{id}.json`) and formats them for the terminal.
+use std::{collections::BTreeMap, collections::HashMap, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::server_time::{format_duration, format_server_time, unlock_timestamp};
+use crate::template::{all_days, Day};
+
+/// Star timestamps of a member for a single day, in seconds since the unix epoch.
+pub type DayStars = [Option<i64>; 2];
+
+/// A member of a private leaderboard.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u64,
+    pub last_star_ts: i64,
+    pub days: BTreeMap<Day, DayStars>,
+}
+
+impl Member {
+    /// Display name, members without a name are shown like on the website.
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// Seconds between part one and part two of a day, if both are solved.
+    pub fn delta(&self, day: Day) -> Option<i64> {
+        match self.days.get(&day)? {
+            [Some(part_1), Some(part_2)] => Some(part_2 - part_1),
+            _ => None,
+        }
+    }
+}
+
+/// A private leaderboard.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    /// Members ordered by local score. Ties are broken by stars and the time of the last star.
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.iter().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+                .then(a.id.cmp(&b.id))
+        });
+        members
+    }
+
+    /// The last day any member has a star for.
+    pub fn last_day(&self) -> Option<Day> {
+        self.members
+            .iter()
+            .filter_map(|member| member.days.keys().last())
+            .max()
+            .copied()
+    }
+
+    /// Renders the ranked table followed by the star timestamps of each member.
+    pub fn render(&self) -> String {
+        let Some(last_day) = self.last_day() else {
+            return format!("No stars on leaderboard for {} yet.", self.event);
+        };
+
+        let days: Vec<Day> = all_days().take_while(|day| *day <= last_day).collect();
+        let ranked = self.ranked();
+        let name_width = ranked
+            .iter()
+            .map(|m| m.display_name().chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+
+        let mut lines = vec![];
+
+        let day_header: String = days
+            .iter()
+            .map(|day| char::from(b'0' + day.into_inner() % 10))
+            .collect();
+        lines.push(format!(
+            "{:>3}  {:<name_width$}  {:>5}  {:>5}  {day_header}",
+            "#", "Name", "Score", "Stars"
+        ));
+
+        for (rank, member) in ranked.iter().enumerate() {
+            let stars: String = days
+                .iter()
+                .map(|day| match member.days.get(day) {
+                    Some([Some(_), Some(_)]) => '★',
+                    Some([Some(_), None]) => '☆',
+                    _ => '·',
+                })
+                .collect();
+            lines.push(format!(
+                "{:>3}  {:<name_width$}  {:>5}  {:>5}  {stars}",
+                format!("{}.", rank + 1),
+                member.display_name(),
+                member.local_score,
+                member.stars
+            ));
+        }
+
+        let year = self.event.parse::<i64>().ok();
+
+        for member in &ranked {
+            if member.days.is_empty() {
+                continue;
+            }
+
+            lines.push(String::new());
+            lines.push(member.display_name());
+
+            for (day, [part_1, part_2]) in &member.days {
+                let unlock = year.map(|year| unlock_timestamp(year, *day));
+                let star = |ts: &Option<i64>| match ts {
+                    Some(ts) => format!(
+                        "{} ({})",
+                        format_server_time(*ts),
+                        unlock.map_or("-".into(), |unlock| format_duration(ts - unlock))
+                    ),
+                    None => "-".into(),
+                };
+                let delta = member.delta(*day).map_or(String::new(), |delta| {
+                    format!("  Δ {}", format_duration(delta))
+                });
+
+                let line = format!(
+                    "  Day {day}  {:<32}  {:<32}{delta}",
+                    star(part_1),
+                    star(part_2)
+                );
+                lines.push(line.trim_end().to_string());
+            }
+        }
+
+        lines.join("\n")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Reads a number that is either a JSON number or a numeric string, as older leaderboards used strings.
+fn get_number(value: Option<&JsonValue>) -> Option<f64> {
+    match value? {
+        JsonValue::Number(n) => Some(*n),
+        JsonValue::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let event = json
+            .get("event")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .ok_or("expected JSON document to have string key `event`.")?;
+
+        let members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected JSON document to have object key `members`.")?;
+
+        Ok(Leaderboard {
+            event,
+            members: members
+                .values()
+                .map(Member::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected member to be a JSON object.")?;
+
+        let id = get_number(json.get("id")).ok_or("Expected member.id to be a number.")? as u64;
+
+        let name = json.get("name").and_then(|v| v.get::<String>()).cloned();
+
+        let local_score = get_number(json.get("local_score"))
+            .ok_or("Expected member.local_score to be a number.")? as u64;
+
+        let stars =
+            get_number(json.get("stars")).ok_or("Expected member.stars to be a number.")? as u64;
+
+        let last_star_ts = get_number(json.get("last_star_ts")).unwrap_or(0.0) as i64;
+
+        let completion = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("Expected member.completion_day_level to be an object.")?;
+
+        let mut days = BTreeMap::new();
+
+        for (day, parts) in completion {
+            let day = Day::from_str(day)
+                .map_err(|_| "Expected keys of member.completion_day_level to be days.")?;
+            let parts = parts
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected completion day to be an object.")?;
+
+            let star = |part: &str| -> Result<Option<i64>, String> {
+                parts
+                    .get(part)
+                    .map(|star| {
+                        star.get::<HashMap<String, JsonValue>>()
+                            .and_then(|star| get_number(star.get("get_star_ts")))
+                            .map(|ts| ts as i64)
+                            .ok_or_else(|| "Expected star to have a `get_star_ts`.".to_string())
+                    })
+                    .transpose()
+            };
+
+            days.insert(day, [star("1")?, star("2")?]);
+        }
+
+        Ok(Member {
+            id,
+            name,
+            local_score,
+            stars,
+            last_star_ts,
+            days,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Leaderboard;
+    use crate::day;
+
+    const LEADERBOARD: &str = r#"{
+        "event": "2024",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "stars": 3, "local_score": 5, "global_score": 0,
+                "last_star_ts": 1733116000,
+                "completion_day_level": {
+                    "1": { "1": { "get_star_ts": 1733029500, "star_index": 1 }, "2": { "get_star_ts": 1733029800, "star_index": 2 } },
+                    "2": { "1": { "get_star_ts": "1733116000", "star_index": 3 } }
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 2, "local_score": 5, "global_score": 0,
+                "last_star_ts": 1733030000,
+                "completion_day_level": {
+                    "1": { "1": { "get_star_ts": 1733029900, "star_index": 1 }, "2": { "get_star_ts": 1733030000, "star_index": 2 } }
+                }
+            },
+            "3": {
+                "id": 3, "name": "Carol", "stars": 0, "local_score": 0, "global_score": 0,
+                "last_star_ts": 0, "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    fn leaderboard() -> Leaderboard {
+        Leaderboard::try_from(LEADERBOARD.to_string()).unwrap()
+    }
+
+    #[test]
+    fn parses_leaderboard() {
+        let leaderboard = leaderboard();
+        assert_eq!(leaderboard.event, "2024");
+        assert_eq!(leaderboard.members.len(), 3);
+        assert_eq!(leaderboard.last_day(), Some(day!(2)));
+
+        let alice = leaderboard.members.iter().find(|m| m.id == 1).unwrap();
+        assert_eq!(alice.days[&day!(1)], [Some(1733029500), Some(1733029800)]);
+        assert_eq!(alice.days[&day!(2)], [Some(1733116000), None]);
+        assert_eq!(alice.delta(day!(1)), Some(300));
+        assert_eq!(alice.delta(day!(2)), None);
+    }
+
+    #[test]
+    fn rejects_invalid_leaderboard() {
+        assert!(Leaderboard::try_from("{}".to_string()).is_err());
+        assert!(Leaderboard::try_from(
+            r#"{ "event": "2024", "members": { "1": {} } }"#.to_string()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn ranks_by_score_then_stars() {
+        let leaderboard = leaderboard();
+        let names: Vec<String> = leaderboard
+            .ranked()
+            .iter()
+            .map(|m| m.display_name())
+            .collect();
+        assert_eq!(names, vec!["Alice", "(anonymous user #2)", "Carol"]);
+    }
+
+    #[test]
+    fn renders_table() {
+        let rendered = leaderboard().render();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "  #  Name                 Score  Stars  12");
+        assert_eq!(lines[1], " 1.  Alice                    5      3  ★☆");
+        assert_eq!(lines[2], " 2.  (anonymous user #2)      5      2  ★·");
+        assert_eq!(lines[3], " 3.  Carol                    0      0  ··");
+        assert!(rendered.contains(
+            "  Day 01  2024-12-01 00:05:00 (5m 00s)      2024-12-01 00:10:00 (10m 00s)     Δ 5m 00s"
+        ));
+    }
+}
@@ -0,0 +1,566 @@
+/// Module that renders the puzzle descriptions saved by `download` to ANSI-styled terminal output.
+/// Puzzle files are markdown converted from the Advent of Code website, which may still contain
+/// inline HTML such as `<em>`, `<code>` or `<pre>` blocks, so both syntaxes are handled.
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const ANSI_UNDERLINE: &str = "\x1b[4m";
+const ANSI_CODE: &str = "\x1b[36m";
+const ANSI_DIM: &str = "\x1b[2m";
+
+const SITE_URL: &str = "https://adventofcode.com";
+const CODE_INDENT: &str = "    ";
+
+/// Styles that are active at a position in the rendered text.
+#[derive(Clone, Debug, Default)]
+struct Style {
+    bold: usize,
+    italic: usize,
+    code: usize,
+    underline: usize,
+}
+
+impl Style {
+    /// Escape sequence that resets the terminal and re-applies the active styles.
+    fn ansi(&self) -> String {
+        let mut s = ANSI_RESET.to_string();
+        if self.bold > 0 {
+            s.push_str(ANSI_BOLD);
+        }
+        if self.italic > 0 {
+            s.push_str(ANSI_ITALIC);
+        }
+        if self.underline > 0 {
+            s.push_str(ANSI_UNDERLINE);
+        }
+        if self.code > 0 {
+            s.push_str(ANSI_CODE);
+        }
+        s
+    }
+}
+
+/// Returns the section of a puzzle description that belongs to `part`.
+/// Part 1 is everything before the "Part Two" heading, part 2 everything after it.
+pub fn select_part(markdown: &str, part: u8) -> Option<String> {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let part_two = lines.iter().position(|line| line.contains("Part Two"));
+
+    let selected = match (part, part_two) {
+        (1, Some(index)) => &lines[..index],
+        (1, None) => &lines[..],
+        (2, Some(index)) => &lines[index..],
+        _ => return None,
+    };
+
+    Some(selected.join("\n"))
+}
+
+/// Renders a markdown document to ANSI-styled text.
+pub fn render(markdown: &str) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut out: Vec<String> = vec![];
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim_start();
+        i += 1;
+
+        if trimmed.starts_with("```") {
+            while i < lines.len() && !lines[i].trim_start().starts_with("```") {
+                out.push(format!("{CODE_INDENT}{ANSI_CODE}{}{ANSI_RESET}", lines[i]));
+                i += 1;
+            }
+            // skip the closing fence.
+            i += 1;
+        } else if trimmed.starts_with("<pre>") {
+            let mut block = vec![line];
+            while !block.last().is_some_and(|l| l.contains("</pre>")) && i < lines.len() {
+                block.push(lines[i]);
+                i += 1;
+            }
+            out.extend(render_pre(&block.join("\n")));
+        } else if let Some(next) = lines.get(i).filter(|next| is_setext_underline(next)) {
+            if trimmed.is_empty() {
+                out.push(horizontal_rule());
+            } else {
+                out.push(heading(trimmed, next.starts_with('=')));
+            }
+            i += 1;
+        } else if let Some(text) = atx_heading(trimmed) {
+            out.push(heading(text, trimmed.starts_with("# ")));
+        } else if is_horizontal_rule(trimmed) {
+            out.push(horizontal_rule());
+        } else if let Some((marker, text)) = list_item(trimmed) {
+            let indent = &line[..line.len() - trimmed.len()];
+            out.push(format!("{indent}  {marker} {}", render_inline(text)));
+        } else if let Some(text) = trimmed.strip_prefix('>') {
+            out.push(format!(
+                "{ANSI_DIM}│{ANSI_RESET} {}",
+                render_inline(text.trim_start())
+            ));
+        } else {
+            out.push(render_inline(line));
+        }
+    }
+
+    out.join("\n")
+}
+
+fn heading(text: &str, primary: bool) -> String {
+    let text = render_inline(text);
+    let rendered = if primary {
+        format!("{ANSI_BOLD}{ANSI_UNDERLINE}{text}{ANSI_RESET}")
+    } else {
+        format!("{ANSI_BOLD}{text}{ANSI_RESET}")
+    };
+    // inline styles reset the terminal, re-apply the heading style after each of them.
+    let style = if primary {
+        format!("{ANSI_RESET}{ANSI_BOLD}{ANSI_UNDERLINE}")
+    } else {
+        format!("{ANSI_RESET}{ANSI_BOLD}")
+    };
+    let end = rendered.len() - ANSI_RESET.len();
+    format!(
+        "{}{ANSI_RESET}",
+        rendered[..end].replace(ANSI_RESET, &style)
+    )
+}
+
+fn horizontal_rule() -> String {
+    format!("{ANSI_DIM}{}{ANSI_RESET}", "─".repeat(40))
+}
+
+fn is_setext_underline(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 3 && (line.chars().all(|c| c == '=') || line.chars().all(|c| c == '-'))
+}
+
+fn is_horizontal_rule(line: &str) -> bool {
+    let line: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    line.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&marker| line.chars().all(|c| c == marker))
+}
+
+fn atx_heading(line: &str) -> Option<&str> {
+    let text = line.trim_start_matches('#');
+    let level = line.len() - text.len();
+    if (1..=6).contains(&level) && (text.is_empty() || text.starts_with(' ')) {
+        Some(text.trim().trim_end_matches('#').trim_end())
+    } else {
+        None
+    }
+}
+
+fn list_item(line: &str) -> Option<(String, &str)> {
+    for bullet in ["* ", "- ", "+ "] {
+        if let Some(text) = line.strip_prefix(bullet) {
+            return Some(("•".into(), text));
+        }
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    let text = line[digits..].strip_prefix(". ")?;
+    (digits > 0).then(|| (format!("{}.", &line[..digits]), text))
+}
+
+/// Renders a `<pre>` block. Only HTML is interpreted inside, markdown syntax is kept as is.
+fn render_pre(block: &str) -> Vec<String> {
+    let content = block
+        .trim()
+        .trim_start_matches("<pre>")
+        .trim_end_matches("</pre>");
+
+    let style = Style {
+        code: 1,
+        ..Style::default()
+    };
+
+    let mut rendered = String::new();
+    let mut active = style.clone();
+    render_spans(content, false, &mut active, &mut rendered);
+
+    let lines: Vec<&str> = rendered.lines().collect();
+    let first = lines
+        .iter()
+        .position(|l| has_text(l))
+        .unwrap_or(lines.len());
+    let last = lines
+        .iter()
+        .rposition(|l| has_text(l))
+        .map_or(first, |i| i + 1);
+
+    lines[first..last]
+        .iter()
+        .map(|line| format!("{CODE_INDENT}{}{line}{ANSI_RESET}", style.ansi()))
+        .collect()
+}
+
+/// Checks whether a rendered line contains visible text besides escape sequences.
+fn has_text(line: &str) -> bool {
+    let mut in_escape = false;
+    line.chars().any(|c| match c {
+        '\x1b' => {
+            in_escape = true;
+            false
+        }
+        c if in_escape => {
+            in_escape = !c.is_ascii_alphabetic();
+            false
+        }
+        c => !c.is_whitespace(),
+    })
+}
+
+/// Renders inline markdown and HTML of a single line.
+fn render_inline(text: &str) -> String {
+    let mut out = String::new();
+    render_spans(text, true, &mut Style::default(), &mut out);
+    if out.contains('\x1b') {
+        out.push_str(ANSI_RESET);
+    }
+    out
+}
+
+fn render_spans(text: &str, markdown: bool, style: &mut Style, out: &mut String) {
+    let chars: Vec<char> = text.chars().collect();
+    let mut links: Vec<Option<String>> = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        match c {
+            '\\' if markdown && chars.get(i + 1).is_some_and(char::is_ascii_punctuation) => {
+                out.push(chars[i + 1]);
+                i += 2;
+            }
+            '<' => match parse_tag(&chars[i..].iter().collect::<String>()) {
+                Some((tag, len)) => {
+                    apply_tag(&tag, style, &mut links, out);
+                    i += len;
+                }
+                None => {
+                    out.push(c);
+                    i += 1;
+                }
+            },
+            '&' => match decode_entity(&chars[i..].iter().take(10).collect::<String>()) {
+                Some((decoded, len)) => {
+                    out.push(decoded);
+                    i += len;
+                }
+                None => {
+                    out.push(c);
+                    i += 1;
+                }
+            },
+            '`' if markdown => match chars[i + 1..].iter().position(|&c| c == '`') {
+                Some(len) => {
+                    style.code += 1;
+                    out.push_str(&style.ansi());
+                    out.extend(&chars[i + 1..i + 1 + len]);
+                    style.code -= 1;
+                    out.push_str(&style.ansi());
+                    i += len + 2;
+                }
+                None => {
+                    out.push(c);
+                    i += 1;
+                }
+            },
+            '*' if markdown && chars.get(i + 1) == Some(&'*') => {
+                style.bold = usize::from(style.bold == 0);
+                out.push_str(&style.ansi());
+                i += 2;
+            }
+            '*' if markdown && (style.italic > 0 || opens_emphasis(&chars, i)) => {
+                style.italic = usize::from(style.italic == 0);
+                out.push_str(&style.ansi());
+                i += 1;
+            }
+            '[' if markdown => match parse_link(&chars[i..]) {
+                Some((label, url, len)) => {
+                    style.underline += 1;
+                    out.push_str(&style.ansi());
+                    render_spans(&label, markdown, style, out);
+                    style.underline -= 1;
+                    out.push_str(&style.ansi());
+                    out.push_str(&format_url(&url));
+                    i += len;
+                }
+                None => {
+                    out.push(c);
+                    i += 1;
+                }
+            },
+            _ => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+}
+
+/// An asterisk opens emphasis if it is followed by text and closed later on.
+fn opens_emphasis(chars: &[char], i: usize) -> bool {
+    chars.get(i + 1).is_some_and(|c| !c.is_whitespace())
+        && chars[i + 1..]
+            .windows(2)
+            .any(|w| !w[0].is_whitespace() && w[1] == '*')
+}
+
+fn parse_link(chars: &[char]) -> Option<(String, String, usize)> {
+    let label_end = chars.iter().position(|&c| c == ']')?;
+    if chars.get(label_end + 1) != Some(&'(') {
+        return None;
+    }
+    let url_len = chars[label_end + 2..].iter().position(|&c| c == ')')?;
+
+    let label = chars[1..label_end].iter().collect();
+    let url = chars[label_end + 2..label_end + 2 + url_len]
+        .iter()
+        .collect();
+
+    Some((label, url, label_end + 3 + url_len))
+}
+
+fn format_url(url: &str) -> String {
+    let url = url.split_whitespace().next().unwrap_or_default();
+    if url.is_empty() || url.starts_with('#') {
+        return String::new();
+    }
+    let url = if url.starts_with('/') {
+        format!("{SITE_URL}{url}")
+    } else {
+        url.to_string()
+    };
+    format!(" {ANSI_DIM}({url}){ANSI_RESET}")
+}
+
+/// An HTML tag, e.g. `<a href="/2024">`.
+#[derive(Debug, PartialEq, Eq)]
+struct Tag {
+    name: String,
+    closing: bool,
+    href: Option<String>,
+}
+
+/// Parses a tag at the start of `s`, returning it and its length in chars.
+fn parse_tag(s: &str) -> Option<(Tag, usize)> {
+    let end = s.find('>')?;
+    let inner = &s[1..end];
+    let (closing, inner) = match inner.strip_prefix('/') {
+        Some(inner) => (true, inner),
+        None => (false, inner),
+    };
+
+    let name: String = inner
+        .chars()
+        .take_while(char::is_ascii_alphanumeric)
+        .collect::<String>()
+        .to_lowercase();
+
+    if name.is_empty() {
+        return None;
+    }
+
+    let href = inner
+        .split_once("href=\"")
+        .and_then(|(_, rest)| rest.split_once('"'))
+        .map(|(href, _)| href.to_string());
+
+    Some((
+        Tag {
+            name,
+            closing,
+            href,
+        },
+        s[..=end].chars().count(),
+    ))
+}
+
+fn apply_tag(tag: &Tag, style: &mut Style, links: &mut Vec<Option<String>>, out: &mut String) {
+    let counter = match tag.name.as_str() {
+        "em" | "strong" | "b" => &mut style.bold,
+        "i" => &mut style.italic,
+        "code" => &mut style.code,
+        "a" => &mut style.underline,
+        // unknown tags such as `<span>` or `<p>` are dropped, keeping their content.
+        _ => return,
+    };
+
+    if tag.closing {
+        *counter = counter.saturating_sub(1);
+    } else {
+        *counter += 1;
+    }
+
+    out.push_str(&style.ansi());
+
+    if tag.name == "a" {
+        if tag.closing {
+            if let Some(href) = links.pop().flatten() {
+                out.push_str(&format_url(&href));
+                out.push_str(&style.ansi());
+            }
+        } else {
+            links.push(tag.href.clone());
+        }
+    }
+}
+
+/// Removes HTML tags from `s` and decodes entities, keeping the text as is otherwise.
+pub fn strip_html(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::with_capacity(s.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let rest = || chars[i..].iter().take(256).collect::<String>();
+        let skipped = match chars[i] {
+            '<' => parse_tag(&rest()).map(|(_, len)| len),
+            '&' => decode_entity(&rest()).map(|(decoded, len)| {
+                out.push(decoded);
+                len
+            }),
+            _ => None,
+        };
+
+        match skipped {
+            Some(len) => i += len,
+            None => {
+                out.push(chars[i]);
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
+
+/// Decodes an HTML entity at the start of `s`, returning it and its length in chars.
+fn decode_entity(s: &str) -> Option<(char, usize)> {
+    let end = s.find(';').filter(|&end| end <= 8)?;
+    let decoded = match &s[1..end] {
+        "lt" => '<',
+        "gt" => '>',
+        "amp" => '&',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        entity => {
+            let code = match entity.strip_prefix("#x") {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => entity.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)?
+        }
+    };
+    Some((decoded, end + 1))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, select_part, ANSI_CODE, ANSI_UNDERLINE};
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    /// Removes escape sequences to compare the visible text.
+    fn plain(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|c| c.is_ascii_alphabetic());
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    const PUZZLE: &str = r"\--- Day 1: Historian Hysteria ---
+----------
+
+The *Chief Historian* is missing. Compare the lists of `location IDs`:
+
+<pre><code>3   4
+4   3 * 2
+</code></pre>
+
+* The first pair is *(3, 4)*.
+* See [the input](/2024/day/1/input).
+
+Your puzzle answer was `11`.
+
+\--- Part Two ---
+----------
+
+Count how often <em>each number</em> appears &amp; multiply.";
+
+    #[test]
+    fn renders_visible_text() {
+        let rendered = plain(&render(PUZZLE));
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(lines[0], "--- Day 1: Historian Hysteria ---");
+        assert_eq!(
+            lines[2],
+            "The Chief Historian is missing. Compare the lists of location IDs:"
+        );
+        assert_eq!(lines[4], "    3   4");
+        assert_eq!(lines[5], "    4   3 * 2");
+        assert_eq!(lines[6], "");
+        assert_eq!(lines[7], "  • The first pair is (3, 4).");
+        assert_eq!(
+            lines[8],
+            "  • See the input (https://adventofcode.com/2024/day/1/input)."
+        );
+        assert_eq!(lines[12], "--- Part Two ---");
+        assert_eq!(lines[14], "Count how often each number appears & multiply.");
+    }
+
+    #[test]
+    fn applies_styles() {
+        let rendered = render("*a* `b` <em>c</em> **d**");
+        assert!(rendered.contains(&format!("{ANSI_RESET}{ANSI_ITALIC}a")));
+        assert!(rendered.contains(&format!("{ANSI_RESET}{ANSI_CODE}b")));
+        assert!(rendered.contains(&format!("{ANSI_RESET}{ANSI_BOLD}c")));
+        assert!(rendered.contains(&format!("{ANSI_RESET}{ANSI_BOLD}d")));
+        assert!(rendered.ends_with(ANSI_RESET));
+    }
+
+    #[test]
+    fn renders_headings() {
+        let rendered = render("# Title\n\n## Sub *title*");
+        assert!(rendered.starts_with(&format!("{ANSI_BOLD}{ANSI_UNDERLINE}Title")));
+        assert_eq!(plain(&rendered), "Title\n\nSub title");
+    }
+
+    #[test]
+    fn keeps_plain_text_unstyled() {
+        assert_eq!(render("2 * 3 = 6\n1 < 2"), "2 * 3 = 6\n1 < 2");
+    }
+
+    #[test]
+    fn renders_fenced_code() {
+        let rendered = render("```\n*not emphasis*\n```\nafter");
+        assert_eq!(plain(&rendered), "    *not emphasis*\nafter");
+    }
+
+    #[test]
+    fn selects_parts() {
+        let part_1 = select_part(PUZZLE, 1).unwrap();
+        assert!(part_1.contains("Day 1"));
+        assert!(!part_1.contains("Part Two"));
+
+        let part_2 = select_part(PUZZLE, 2).unwrap();
+        assert!(part_2.starts_with("\\--- Part Two ---"));
+        assert!(!part_2.contains("Day 1"));
+
+        assert_eq!(select_part("only part one", 2), None);
+        assert_eq!(select_part("x", 3), None);
+    }
+}
@@ -1,39 +1,79 @@
-use std::{env, fs};
-
 pub mod aoc_cli;
 pub mod commands;
+pub mod differential;
+pub mod registry;
 pub mod runner;
+#[cfg(feature = "today")]
+pub mod unlock;
 
 pub use day::*;
 
+mod answers;
+mod archive;
+mod config;
 mod day;
+mod encryption;
+mod external;
+mod fingerprints;
+mod html_report;
+mod inputs;
+mod leaderboard;
+mod markdown;
+mod puzzle;
 mod readme_benchmarks;
+#[cfg(feature = "solutions")]
+mod repl;
+#[cfg(feature = "solutions")]
+mod rpc;
 mod run_multi;
+#[cfg(feature = "solutions")]
+mod scale;
+mod server;
+mod server_time;
+#[cfg(feature = "solutions")]
+mod shrink;
+mod solve_times;
+mod status;
+mod svg_chart;
 mod timings;
+mod tui;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Loads the project configuration from `aoc.toml`, returns an error message if it is invalid.
+pub fn load_config() -> Result<(), String> {
+    config::load().map(|_| ())
+}
+
 /// Helper function that reads a text file to a string.
+/// If the file does not exist, its encrypted counterpart is decrypted instead.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    let filepath = config::get().data_folder(folder).join(format!("{day}.txt"));
+    let f = encryption::read_to_string(&filepath);
+    f.unwrap_or_else(|e| panic!("could not open input file: {e}"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
+    let filepath = config::get()
+        .data_folder(folder)
         .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    let f = encryption::read_to_string(&filepath);
+    f.unwrap_or_else(|e| panic!("could not open input file: {e}"))
+}
+
+/// Helper function that reads a text file to string, appending a suffix. E.g. like `01-ex2.txt`.
+#[must_use]
+pub fn read_file_suffix(folder: &str, day: Day, suffix: &str) -> String {
+    let filepath = config::get()
+        .data_folder(folder)
+        .join(format!("{day}-{suffix}.txt"));
+    let f = encryption::read_to_string(&filepath);
+    f.unwrap_or_else(|e| panic!("could not open input file: {e}"))
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
@@ -52,8 +92,7 @@ macro_rules! solution {
     };
 
     (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        $crate::solution!(@entry $day, $( [$func, $part] )*);
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
@@ -61,8 +100,29 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
+
+            if std::env::args().any(|x| x == "--all-inputs") {
+                run_all_inputs(DAY, &[$( ($part, &|input: &str| $func(input).map(|answer| answer.to_string())) ),*]);
+                return;
+            }
+
             let input = $crate::template::read_file("inputs", DAY);
+            check_input(DAY, &input);
             $( run_part($func, &input, DAY, $part); )*
         }
     };
+
+    (@entry $day:expr, $( [$func:expr, $part:expr] )*) => {
+        /// The current day.
+        const DAY: $crate::template::Day = $crate::day!($day);
+
+        /// Entry of this day in the solution registry of the library.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution = $crate::template::registry::Solution {
+            day: DAY,
+            parts: &[$( ($part, |input: &str| $func(input).map(|answer| answer.to_string())) ),*],
+            entry_points: &[],
+            generator: None,
+        };
+    };
 }
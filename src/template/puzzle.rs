@@ -0,0 +1,315 @@
+/// Module that extracts example inputs and their expected answers from downloaded puzzle descriptions,
+/// and updates descriptions once part two unlocks. Examples are taken from `<pre><code>` blocks (or fenced
+/// code blocks), answers from emphasized code spans such as `<code><em>11</em></code>` that follow them.
+use std::{
+    fs, iter,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+use crate::template::markdown::{self, select_part, strip_html};
+use crate::template::{aoc_cli, config, encryption, Day};
+
+/// Returns the title of a puzzle, e.g. `Historian Hysteria` for `--- Day 1: Historian Hysteria ---`.
+pub fn extract_title(markdown: &str) -> Option<String> {
+    let re = Regex::new(r"-{3} Day \d+: (.+?) -{3}").expect("valid regex");
+    re.captures(markdown)
+        .map(|captures| strip_html(captures[1].trim()))
+        .filter(|title| !title.is_empty())
+}
+
+/// Returns the content of all code blocks in the order they appear.
+pub fn extract_examples(markdown: &str) -> Vec<String> {
+    code_blocks(markdown)
+        .into_iter()
+        .map(|(_, block)| block)
+        .collect()
+}
+
+/// Returns the expected example answer for each part, if one can be found.
+pub fn extract_answers(markdown: &str) -> [Option<String>; 2] {
+    [1, 2].map(|part| select_part(markdown, part).and_then(|section| expected_answer(&section)))
+}
+
+/// Returns the index of the example that the part one answer refers to: the last code block
+/// of part one if an answer was found, the first code block otherwise.
+pub fn primary_example(markdown: &str) -> usize {
+    let part_one = select_part(markdown, 1).unwrap_or_default();
+    if expected_answer(&part_one).is_some() {
+        code_blocks(&part_one).len().saturating_sub(1)
+    } else {
+        0
+    }
+}
+
+/// Code blocks of a document, along with the byte offset where each block ends.
+fn code_blocks(markdown: &str) -> Vec<(usize, String)> {
+    let re = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>|(?m)^```[^\n]*\n((?s).*?)^```")
+        .expect("valid regex");
+
+    re.captures_iter(markdown)
+        .filter_map(|captures| {
+            let end = captures.get(0)?.end();
+            let block = match captures.get(1) {
+                Some(html) => strip_html(html.as_str()),
+                None => captures.get(2)?.as_str().to_string(),
+            };
+
+            if block.trim().is_empty() {
+                return None;
+            }
+
+            let mut block = block.trim_start_matches('\n').to_string();
+            if !block.ends_with('\n') {
+                block.push('\n');
+            }
+            Some((end, block))
+        })
+        .collect()
+}
+
+/// Finds the answer of the example in a section of the puzzle: the last emphasized code span
+/// after the last code block. Sections without their own example, which reuse the example of
+/// the previous part, are searched entirely.
+fn expected_answer(section: &str) -> Option<String> {
+    let re = Regex::new(
+        r"<code><em>(.+?)</em></code>|<em><code>(.+?)</code></em>|`\*(.+?)\*`|\*`(.+?)`\*|\*\*`(.+?)`\*\*",
+    )
+    .expect("valid regex");
+
+    let start = code_blocks(section).last().map_or(0, |(end, _)| *end);
+
+    section[start..]
+        .lines()
+        .filter(|line| !line.contains("Your puzzle answer was"))
+        .flat_map(|line| {
+            re.captures_iter(line)
+                .filter_map(|captures| captures.iter().skip(1).flatten().next())
+                .map(|m| strip_html(m.as_str()).replace('\\', ""))
+                .collect::<Vec<_>>()
+        })
+        .last()
+}
+
+/// Returns the lines of `new` that are not part of `old`, based on their longest common subsequence.
+pub fn added_lines<'a>(old: &str, new: &'a str) -> Vec<&'a str> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // lcs[i][j] holds the length of the longest common subsequence of old[i..] and new[j..].
+    let mut lcs = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut added = vec![];
+
+    while j < new.len() {
+        if i < old.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if i < old.len() && lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            added.push(new[j]);
+            j += 1;
+        }
+    }
+
+    added
+}
+
+/// Returns the examples of `new` that are not part of `old`.
+pub fn new_examples(old: &str, new: &str) -> Vec<String> {
+    let existing = extract_examples(old);
+    extract_examples(new)
+        .into_iter()
+        .filter(|example| !existing.contains(example))
+        .collect()
+}
+
+/// Re-downloads the puzzle description of a day after part one was solved.
+/// The previous version is kept as `NN-1.md`, the newly added text is printed and new examples
+/// are saved as example files, the first one as `NN-2.txt`.
+pub fn refresh(day: Day) -> Result<(), String> {
+    let config = config::get();
+    let puzzle_path = config.puzzle_path(day);
+    let old = encryption::read_to_string(&puzzle_path).unwrap_or_default();
+
+    if !old.is_empty() {
+        let previous_path = config.data_folder("puzzles").join(format!("{day}-1.md"));
+        fs::write(&previous_path, &old).map_err(|e| e.to_string())?;
+    }
+
+    aoc_cli::download_puzzle(day).map_err(|e| e.to_string())?;
+    let new = fs::read_to_string(&puzzle_path).map_err(|e| e.to_string())?;
+
+    let added = added_lines(&old, &new);
+    if added.iter().all(|line| line.trim().is_empty()) {
+        println!("The puzzle description did not change.");
+        return Ok(());
+    }
+
+    println!("---");
+    println!("{}", markdown::render(added.join("\n").trim()));
+    println!("---");
+
+    let folder = config.data_folder("examples");
+    for example in new_examples(&old, &new) {
+        let Some(path) = next_example_path(&folder, day, &example) else {
+            continue;
+        };
+        fs::write(&path, example).map_err(|e| e.to_string())?;
+        println!("Created example file \"{}\" from puzzle", path.display());
+    }
+
+    Ok(())
+}
+
+/// Returns the path of a new part two example: `01-2.txt` for use with `read_file_part`, or the first
+/// free `01-ex2.txt`, `01-ex3.txt`... for use with `read_file_suffix` if `01-2.txt` holds another example.
+/// Returns `None` if an example with the same content exists already.
+fn next_example_path(folder: &Path, day: Day, example: &str) -> Option<PathBuf> {
+    iter::once(format!("{day}-2.txt"))
+        .chain((2..).map(|n| format!("{day}-ex{n}.txt")))
+        .find_map(|name| {
+            let path = folder.join(name);
+            match fs::read_to_string(&path) {
+                Ok(content) if content == example => Some(None),
+                Ok(_) => None,
+                Err(_) => Some(Some(path)),
+            }
+        })?
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use crate::day;
+
+    use super::{
+        added_lines, extract_answers, extract_examples, extract_title, new_examples,
+        next_example_path, primary_example,
+    };
+
+    const HTML_PUZZLE: &str = "\\--- Day 1: Historian Hysteria ---
+----------
+
+For example:
+
+<pre><code>3   4
+4   3
+</code></pre>
+
+Lists look like <code>&lt;a&gt;</code> and an inline <code><em>5</em></code> before the example.
+
+<pre><code>1 &lt; 2
+<em>3</em> 9
+</code></pre>
+
+In this example, the total distance is <code><em>11</em></code>.
+
+Your puzzle answer was `1830467`.
+
+\\--- Part Two ---
+----------
+
+In the example above, the similarity score is <em><code>31</code></em>.
+";
+
+    #[test]
+    fn extracts_html_examples() {
+        assert_eq!(
+            extract_examples(HTML_PUZZLE),
+            vec!["3   4\n4   3\n".to_string(), "1 < 2\n3 9\n".to_string()]
+        );
+    }
+
+    #[test]
+    fn extracts_fenced_examples() {
+        let markdown = "Example:\n\n```\nabc\ndef\n```\n\nThe answer is `*42*`.";
+        assert_eq!(extract_examples(markdown), vec!["abc\ndef\n".to_string()]);
+        assert_eq!(extract_answers(markdown), [Some("42".into()), None]);
+    }
+
+    #[test]
+    fn extracts_answers_after_examples() {
+        assert_eq!(
+            extract_answers(HTML_PUZZLE),
+            [Some("11".into()), Some("31".into())]
+        );
+    }
+
+    #[test]
+    fn selects_example_of_answer() {
+        assert_eq!(primary_example(HTML_PUZZLE), 1);
+        assert_eq!(primary_example("```\na\n```\n```\nb\n```"), 0);
+    }
+
+    #[test]
+    fn extracts_titles() {
+        assert_eq!(
+            extract_title(HTML_PUZZLE),
+            Some("Historian Hysteria".into())
+        );
+        assert_eq!(
+            extract_title("## --- Day 25: Code Chronicle ---"),
+            Some("Code Chronicle".into())
+        );
+        assert_eq!(extract_title("no title"), None);
+    }
+
+    #[test]
+    fn ignores_missing_answers() {
+        assert_eq!(extract_answers("no answer here"), [None, None]);
+        assert!(extract_examples("no examples").is_empty());
+    }
+
+    #[test]
+    fn finds_added_lines() {
+        let old = "a\nb\nc\n";
+        let new = "a\nb\nanswer\nc\npart two\n";
+        assert_eq!(added_lines(old, new), vec!["answer", "part two"]);
+        assert_eq!(added_lines(new, new), Vec::<&str>::new());
+        assert_eq!(added_lines("", "x"), vec!["x"]);
+    }
+
+    #[test]
+    fn finds_new_examples() {
+        let old = "```\na\n```";
+        let new = "```\na\n```\n\n--- Part Two ---\n\n```\nb\n```";
+        assert_eq!(new_examples(old, new), vec!["b\n".to_string()]);
+        assert!(new_examples(new, new).is_empty());
+    }
+
+    #[test]
+    fn saves_part_two_examples_for_read_file_part() {
+        let folder = env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        fs::create_dir_all(&folder).unwrap();
+        let day = day!(1);
+
+        // a candidate of part one from scaffolding does not take the part two name.
+        fs::write(folder.join("01-ex2.txt"), "candidate\n").unwrap();
+        let path = next_example_path(&folder, day, "part two\n").unwrap();
+        assert_eq!(path, folder.join("01-2.txt"));
+        fs::write(&path, "part two\n").unwrap();
+
+        assert_eq!(next_example_path(&folder, day, "part two\n"), None);
+        assert_eq!(next_example_path(&folder, day, "candidate\n"), None);
+        assert_eq!(
+            next_example_path(&folder, day, "another\n"),
+            Some(folder.join("01-ex3.txt"))
+        );
+
+        fs::remove_dir_all(&folder).unwrap();
+    }
+}
@@ -2,8 +2,12 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
+use crate::template::config;
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::server_time::format_duration;
+use crate::template::solve_times::SolveTimes;
+use crate::template::svg_chart::{self, Scale};
 use crate::template::timings::Timings;
-use crate::template::Day;
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -25,11 +29,6 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -52,115 +51,159 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+fn construct_table(
+    prefix: &str,
+    timings: Timings,
+    total_millis: f64,
+    solve_times: Option<&SolveTimes>,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if solve_times.is_some() {
+        lines.push("| Day | Part 1 | Part 2 | Solve time |".into());
+        lines.push("| :---: | :---: | :---:  | :---: |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
         let path = get_path_for_bin(timing.day);
-        lines.push(format!(
+        let mut line = format!(
             "| [Day {}]({}) | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        );
+
+        if let Some(solve_times) = solve_times {
+            let solve_time = solve_times
+                .get(timing.day)
+                .and_then(|t| t.time_to_part(2).or_else(|| t.time_to_part(1)))
+                .map_or_else(|| "-".into(), format_duration);
+            line.push_str(&format!(" {solve_time} |"));
+        }
+
+        lines.push(line);
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
+    lines.push(String::new());
+    lines.push(format!(
+        "![Benchmark chart](./{})",
+        config::get().chart_file.trim_start_matches("./")
+    ));
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+fn update_content(
+    s: &mut String,
+    timings: Timings,
+    total_millis: f64,
+    solve_times: Option<&SolveTimes>,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", timings, total_millis, solve_times);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
+/// Empties the benchmark table, returns the previous table including its markers.
+pub fn take_table(s: &mut String) -> Result<String, Error> {
+    let positions = locate_table(s)?;
+    let table = s[positions.pos_start..positions.pos_end].to_string();
+    s.replace_range(positions.pos_start..positions.pos_end, MARKER);
+    Ok(table)
+}
+
+fn write_chart(timings: &Timings) -> Result<(), Error> {
+    let config = config::get();
+    let path = config.path(&config.chart_file);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, svg_chart::bar_chart(timings, Scale::Log))?;
+    Ok(())
+}
+
 pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    let config = config::get();
+    let path = config.path(&config.readme_file);
+    write_chart(&timings)?;
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    let solve_times = config.readme_solve_times.then(SolveTimes::read_from_file);
+    update_content(&mut readme, timings, total_millis, solve_times.as_ref())?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
-
-    fn get_mock_timings() -> Timings {
-        Timings {
-            data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
-                },
-            ],
-        }
-    }
+    use super::{take_table, update_content, MARKER};
+    use crate::template::solve_times::{SolveTime, SolveTimes};
+    use crate::{day, template::timings::get_mock_timings};
 
     #[test]
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
 
+    #[test]
+    fn references_chart_inside_markers() {
+        let mut s = format!("foo\n{}{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
+        let (start, end) = (s.find(MARKER).unwrap(), s.rfind(MARKER).unwrap());
+        assert_eq!(s.matches("![Benchmark chart]").count(), 1);
+        assert!((start..end).contains(&s.find("![Benchmark chart]").unwrap()));
+    }
+
+    #[test]
+    fn takes_existing_benchmarks() {
+        let mut s = format!("foo\n{}{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
+        let table = take_table(&mut s).unwrap();
+        assert!(table.starts_with(MARKER) && table.ends_with(MARKER));
+        assert!(table.contains("## Benchmarks"));
+        assert_eq!(s, format!("foo\n{}\nbaz", MARKER));
+    }
+
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, None).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -171,13 +214,34 @@ mod tests {
             "| :---: | :---: | :---:  |",
             "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
             "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 4](./src/bin/04.rs) | `40ms` | `-` |",
             "",
             "**Total: 190.00ms**",
+            "",
+            "![Benchmark chart](./.assets/benchmarks.svg)",
             "<!--- benchmarking table --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn adds_solve_time_column() {
+        let solve_times = SolveTimes {
+            data: vec![SolveTime {
+                day: day!(1),
+                started: Some(0),
+                part_1: Some(300),
+                part_2: Some(3723),
+            }],
+        };
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0, Some(&solve_times)).unwrap();
+
+        assert!(s.contains("| Day | Part 1 | Part 2 | Solve time |"));
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` | 1h 02m 03s |"));
+        assert!(s.contains("| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` | - |"));
+    }
 }
@@ -0,0 +1,305 @@
+/// Module that exposes the solutions in `src/bin` as functions of the library.
+/// With the `solutions` feature, the build script compiles every solution into a module of this file,
+/// the `solution!` macro of each module defines its entry of the registry.
+use std::error::Error;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::template::differential::Generator;
+use crate::template::Day;
+
+/// A solution part that maps an input to its answer.
+pub type PartFn = fn(&str) -> Option<String>;
+
+/// Calls a helper of a solution with an input and arguments parsed from strings, returns its formatted result.
+pub type EntryFn = fn(&str, &[String]) -> Result<String, String>;
+
+/// A named, parameterized function of a solution, declared with [`entry_points!`](crate::entry_points).
+#[derive(Clone, Copy, Debug)]
+pub struct EntryPoint {
+    pub name: &'static str,
+    /// Parameters as written in the declaration, e.g. `blinks: u32`.
+    pub params: &'static [&'static str],
+    /// Lines of the doc comment.
+    pub doc: &'static [&'static str],
+    pub run: EntryFn,
+}
+
+impl EntryPoint {
+    /// Signature for help texts, e.g. `solve(blinks: u32)`.
+    pub fn signature(&self) -> String {
+        format!("{}({})", self.name, self.params.join(", "))
+    }
+}
+
+/// The parts of a single day.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub day: Day,
+    pub parts: &'static [(u8, PartFn)],
+    pub entry_points: &'static [EntryPoint],
+    /// Generator of inputs of a requested size, registered with [`generator!`](crate::generator).
+    pub generator: Option<Generator>,
+}
+
+impl Solution {
+    pub fn part(&self, part: u8) -> Option<PartFn> {
+        self.parts.iter().find(|(p, _)| *p == part).map(|(_, f)| *f)
+    }
+
+    pub fn entry_point(&self, name: &str) -> Option<&'static EntryPoint> {
+        self.entry_points.iter().find(|e| e.name == name)
+    }
+
+    /// The requested part, or every part if none is requested.
+    pub fn select_parts(&self, part: Option<u8>) -> Result<Vec<(u8, PartFn)>, String> {
+        match part {
+            Some(part) => self
+                .part(part)
+                .map(|func| vec![(part, func)])
+                .ok_or_else(|| SolveError::UnknownPart(self.day, part).to_string()),
+            None => Ok(self.parts.to_vec()),
+        }
+    }
+
+    /// Part numbers implemented by this solution.
+    pub fn part_numbers(&self) -> impl Iterator<Item = u8> + '_ {
+        self.parts.iter().map(|(part, _)| *part)
+    }
+}
+
+impl std::fmt::Debug for Solution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Solution")
+            .field("day", &self.day)
+            .field("parts", &self.part_numbers().collect::<Vec<_>>())
+            .field("entry_points", &self.entry_points)
+            .field("generator", &self.generator.is_some())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    UnknownDay(Day),
+    UnknownPart(Day, u8),
+    /// The part returned `None` for the input.
+    NoAnswer(Day, u8),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::UnknownDay(day) => write!(f, "day {day} has no solution"),
+            SolveError::UnknownPart(day, part) => {
+                write!(f, "day {day} has no solution for part {part}")
+            }
+            SolveError::NoAnswer(day, part) => {
+                write!(f, "day {day} part {part} returned no answer")
+            }
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// Calls a function of a solution, returns the message of a panic as error.
+pub fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(func)).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|s| (*s).to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default()
+    })
+}
+
+/// Paths used by the solutions, with `solution!` replaced by [`registry_solution!`](crate::registry_solution)
+/// and `differential!` replaced by [`registry_differential!`](crate::registry_differential).
+#[cfg(feature = "solutions")]
+#[doc(hidden)]
+pub mod shim {
+    pub use crate::registry_differential as differential;
+    pub use crate::registry_solution as solution;
+    pub use crate::*;
+}
+
+#[cfg(feature = "solutions")]
+mod generated {
+    use super::Solution;
+
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
+/// Solutions of every day, ordered by day.
+#[cfg(feature = "solutions")]
+pub fn solutions() -> &'static [Solution] {
+    generated::SOLUTIONS
+}
+
+#[cfg(feature = "solutions")]
+pub fn get(day: Day) -> Option<&'static Solution> {
+    solutions().iter().find(|s| s.day == day)
+}
+
+/// Looks up the solution of a day for a command, the error hints at scaffolding the day.
+#[cfg(feature = "solutions")]
+pub fn require(day: Day) -> Result<&'static Solution, String> {
+    get(day).ok_or_else(|| {
+        format!(
+            "{}, scaffold it with `cargo scaffold {day}`",
+            SolveError::UnknownDay(day)
+        )
+    })
+}
+
+/// Runs a part of a day on an input and returns its answer.
+#[cfg(feature = "solutions")]
+pub fn solve(day: Day, part: u8, input: &str) -> Result<String, SolveError> {
+    solve_in(solutions(), day, part, input)
+}
+
+/// Like [`solve`], but looks up the day in the given solutions.
+#[cfg(feature = "solutions")]
+fn solve_in(solutions: &[Solution], day: Day, part: u8, input: &str) -> Result<String, SolveError> {
+    let solution = solutions
+        .iter()
+        .find(|s| s.day == day)
+        .ok_or(SolveError::UnknownDay(day))?;
+    let func = solution
+        .part(part)
+        .ok_or(SolveError::UnknownPart(day, part))?;
+    func(input).ok_or(SolveError::NoAnswer(day, part))
+}
+
+/// Defines the registry entry of a solution compiled into the library. Takes the arguments of `solution!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! registry_solution {
+    ($day:expr) => {
+        $crate::solution!(@entry $day, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, 1) => {
+        $crate::solution!(@entry $day, [part_one, 1]);
+    };
+    ($day:expr, 2) => {
+        $crate::solution!(@entry $day, [part_two, 2]);
+    };
+}
+
+/// Drops the differential tests of a solution compiled into the library, they run with the solution binary.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! registry_differential {
+    ($($tokens:tt)*) => {};
+}
+
+/// Declares helpers of a solution that can be called with arguments, e.g. from `cargo repl`.
+/// Each entry point receives the input and arguments parsed with [`FromStr`](std::str::FromStr),
+/// the result is formatted with its `Debug` implementation.
+///
+/// ```ignore
+/// advent_of_code::entry_points! {
+///     /// Counts the stones after a number of blinks.
+///     blinks(input, blinks: u32) => solve(input, blinks),
+/// }
+/// ```
+#[macro_export]
+macro_rules! entry_points {
+    ($( $(#[doc = $doc:literal])* $name:ident($input:ident $(, $arg:ident: $ty:ty)*) => $body:expr ),* $(,)?) => {
+        /// Entry points of this day in the solution registry of the library.
+        #[allow(dead_code)]
+        pub const ENTRY_POINTS: &[$crate::template::registry::EntryPoint] = &[$(
+            $crate::template::registry::EntryPoint {
+                name: stringify!($name),
+                params: &[$(concat!(stringify!($arg), ": ", stringify!($ty))),*],
+                doc: &[$($doc),*],
+                run: |$input: &str, args: &[String]| {
+                    let _ = $input;
+                    let expected: &[&str] = &[$(stringify!($arg)),*];
+                    if args.len() != expected.len() {
+                        return Err(format!(
+                            "expected {} argument(s): {}",
+                            expected.len(),
+                            expected.join(", ")
+                        ));
+                    }
+                    let mut args = args.iter();
+                    $(
+                        let $arg: $ty = args
+                            .next()
+                            .expect("argument count was checked")
+                            .parse()
+                            .map_err(|e| format!("invalid `{}`: {e}", stringify!($arg)))?;
+                    )*
+                    Ok(format!("{:?}", $body))
+                },
+            }
+        ),*];
+    };
+}
+
+#[cfg(all(feature = "test_lib", feature = "solutions"))]
+mod tests {
+    use super::{get, solutions, solve, solve_in, Solution, SolveError};
+    use crate::day;
+
+    #[test]
+    fn lists_solutions_by_day() {
+        let days: Vec<_> = solutions().iter().map(|s| s.day).collect();
+        assert!(days.contains(&day!(1)) && days.contains(&day!(11)));
+        let mut sorted = days.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(days, sorted);
+    }
+
+    #[test]
+    fn solves_parts() {
+        let solution = get(day!(1)).expect("day 1 is registered");
+        assert_eq!(solution.part_numbers().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(solution.select_parts(None).unwrap().len(), 2);
+        assert_eq!(solution.select_parts(Some(2)).unwrap()[0].0, 2);
+        assert_eq!(
+            solution.select_parts(Some(3)).unwrap_err(),
+            "day 01 has no solution for part 3"
+        );
+        assert_eq!(
+            solve(day!(1), 1, "3 4\n4 3\n2 5\n1 3\n3 9\n3 3\n"),
+            Ok("11".into())
+        );
+        assert_eq!(
+            solve(day!(1), 3, ""),
+            Err(SolveError::UnknownPart(day!(1), 3))
+        );
+    }
+
+    #[test]
+    fn calls_entry_points() {
+        let entry_point = get(day!(11))
+            .and_then(|s| s.entry_point("solve"))
+            .expect("day 11 registers a `solve` entry point");
+        assert_eq!(entry_point.signature(), "solve(blinks: u32)");
+        assert_eq!(
+            (entry_point.run)("125 17", &["6".into()]),
+            Ok("Some(22)".into())
+        );
+        assert!((entry_point.run)("125 17", &[]).is_err());
+        assert!((entry_point.run)("125 17", &["x".into()]).is_err());
+    }
+
+    #[test]
+    fn reports_unknown_days() {
+        let solutions = [Solution {
+            day: day!(1),
+            parts: &[(1, |_| Some("1".into()))],
+            entry_points: &[],
+            generator: None,
+        }];
+        assert_eq!(solve_in(&solutions, day!(1), 1, ""), Ok("1".into()));
+        assert_eq!(
+            solve_in(&solutions, day!(2), 1, ""),
+            Err(SolveError::UnknownDay(day!(2)))
+        );
+    }
+}
@@ -0,0 +1,217 @@
+/// Module that parses and evaluates the commands of `cargo repl`.
+/// Parts and entry points of a solution are called in-process on an input that is loaded once.
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::template::inputs;
+use crate::template::registry::{self, Solution};
+use crate::template::{config, encryption, Day};
+
+/// Name of the example input of a day, e.g. `example` for `01.txt` and `example-2` for `01-2.txt`.
+pub const EXAMPLE_INPUT: &str = "example";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    /// Runs a part on the input.
+    Part(u8),
+    /// Calls an entry point with arguments.
+    Call {
+        name: String,
+        args: Vec<String>,
+    },
+    /// Switches to another input.
+    Input(String),
+    /// Rebuilds the solutions and restarts the session.
+    Rebuild,
+    Help,
+    Quit,
+    Empty,
+}
+
+/// Splits a line into words. Words that contain whitespace can be quoted with `"`.
+pub fn tokenize(line: &str) -> Result<Vec<String>, String> {
+    let mut words = vec![];
+    let mut word: Option<String> = None;
+    let mut quoted = false;
+
+    for c in line.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                word.get_or_insert_with(String::new);
+            }
+            c if c.is_whitespace() && !quoted => words.extend(word.take()),
+            c => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+
+    if quoted {
+        return Err("missing closing quote".into());
+    }
+
+    words.extend(word);
+    Ok(words)
+}
+
+pub fn parse(line: &str) -> Result<Command, String> {
+    let mut words = tokenize(line)?.into_iter();
+
+    let Some(first) = words.next() else {
+        return Ok(Command::Empty);
+    };
+
+    let rest: Vec<String> = words.collect();
+
+    let command = match first.as_str() {
+        "1" | "p1" | "part_one" => Command::Part(1),
+        "2" | "p2" | "part_two" => Command::Part(2),
+        ":input" | ":i" => match rest.as_slice() {
+            [name] => return Ok(Command::Input(name.clone())),
+            _ => return Err("usage: :input <default|example|name|path>".into()),
+        },
+        ":rebuild" | ":r" => Command::Rebuild,
+        ":help" | ":h" | "help" => Command::Help,
+        ":quit" | ":q" | "quit" | "exit" => Command::Quit,
+        command if command.starts_with(':') => {
+            return Err(format!(
+                "unknown command `{command}`, type `:help` for help"
+            ))
+        }
+        name => {
+            return Ok(Command::Call {
+                name: name.to_string(),
+                args: rest,
+            })
+        }
+    };
+
+    if rest.is_empty() {
+        Ok(command)
+    } else {
+        Err(format!("`{first}` takes no arguments"))
+    }
+}
+
+/// Resolves the name of an input of a day to its file. Besides the default and example inputs,
+/// named inputs of the day and paths are accepted.
+pub fn input_path(day: Day, name: &str) -> PathBuf {
+    let config = config::get();
+
+    if name == inputs::DEFAULT_INPUT {
+        return config.input_path(day);
+    }
+
+    if let Some(suffix) = name.strip_prefix(EXAMPLE_INPUT) {
+        if suffix.is_empty() {
+            return config.example_path(day);
+        }
+        if let Some(part) = suffix.strip_prefix('-') {
+            return config
+                .data_folder("examples")
+                .join(format!("{day}-{part}.txt"));
+        }
+    }
+
+    inputs::list(day)
+        .into_iter()
+        .find(|input| input.name == name)
+        .map_or_else(|| PathBuf::from(name), |input| input.path)
+}
+
+pub fn read_input(day: Day, name: &str) -> Result<String, String> {
+    let path = input_path(day, name);
+    encryption::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Runs a part or an entry point and returns its formatted result and duration.
+pub fn call(
+    solution: &Solution,
+    input: &str,
+    command: &Command,
+) -> Result<(String, Duration), String> {
+    let panicked = |message: String| format!("panicked: {message}");
+    let timer = Instant::now();
+
+    let answer = match command {
+        Command::Part(part) => {
+            let func = solution
+                .part(*part)
+                .ok_or_else(|| format!("day {} has no part {part}", solution.day))?;
+            registry::catch_panic(|| func(input))
+                .map_err(panicked)?
+                .unwrap_or_else(|| "✖".into())
+        }
+        Command::Call { name, args } => {
+            let entry_point = solution
+                .entry_point(name)
+                .ok_or_else(|| format!("unknown entry point `{name}`, type `:help` for help"))?;
+            registry::catch_panic(|| (entry_point.run)(input, args)).map_err(panicked)??
+        }
+        command => return Err(format!("{command:?} is not a call")),
+    };
+
+    Ok((answer, timer.elapsed()))
+}
+
+/// Lists the commands, parts and entry points of a solution.
+pub fn help(solution: &Solution) -> Vec<String> {
+    let mut lines = vec![format!("Day {}", solution.day), String::new()];
+
+    for part in solution.part_numbers() {
+        lines.push(format!("  {part}    run part {part} on the input"));
+    }
+
+    for entry_point in solution.entry_points {
+        lines.push(format!("  {}", entry_point.signature()));
+        for doc in entry_point.doc {
+            lines.push(format!("      {}", doc.trim()));
+        }
+    }
+
+    lines.extend(
+        [
+            "",
+            "  :input <default|example|name|path>   switch the input",
+            "  :rebuild                             rebuild and restart, then repeat the last call",
+            "  :quit",
+        ]
+        .map(String::from),
+    );
+
+    lines
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, tokenize, Command};
+
+    #[test]
+    fn tokenizes_quoted_words() {
+        assert_eq!(
+            tokenize(r#"call 40 "a b" """#).unwrap(),
+            vec!["call", "40", "a b", ""]
+        );
+        assert!(tokenize(r#"call "open"#).is_err());
+        assert!(tokenize("   ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(parse("p2"), Ok(Command::Part(2)));
+        assert_eq!(
+            parse("solve 40"),
+            Ok(Command::Call {
+                name: "solve".into(),
+                args: vec!["40".into()]
+            })
+        );
+        assert_eq!(
+            parse(":i example-2"),
+            Ok(Command::Input("example-2".into()))
+        );
+        assert_eq!(parse(""), Ok(Command::Empty));
+        assert!(parse("1 2").is_err());
+        assert!(parse(":nope").is_err());
+        assert!(parse(":input").is_err());
+    }
+}
@@ -0,0 +1,380 @@
+/// Module that implements a line-delimited JSON-RPC 2.0 service on top of the solution registry.
+/// Every request is a single line of JSON, every response is written as a single line.
+///
+/// Methods:
+///  - `listDays`: the days with a solution and their parts.
+///  - `solve`: runs the parts of a day once. Params: `day`, optional `part` and `input`.
+///  - `bench`: like `solve`, but benches each part with the bench settings of the project.
+///  - `test`: runs the parts of a day on all of its inputs and compares them with the expected answers.
+///
+/// Without an `input` param, the input file of the day is used.
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use tinyjson::JsonValue;
+
+use crate::template::inputs::{self, Outcome};
+use crate::template::registry::{self, PartFn, Solution};
+use crate::template::{config, encryption, runner, Day};
+
+const PARSE_ERROR: i32 = -32700;
+const INVALID_REQUEST: i32 = -32600;
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
+/// Reserved for implementation-defined server errors, e.g. a missing input file.
+const SERVER_ERROR: i32 = -32000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcError {
+    pub code: i32,
+    pub message: String,
+}
+
+impl RpcError {
+    fn new(code: i32, message: impl Into<String>) -> Self {
+        RpcError {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+type Params = HashMap<String, JsonValue>;
+
+/// Handles a request line and returns the response line. Notifications, i.e. request objects without an `id`,
+/// get no response.
+pub fn handle_line(line: &str) -> Option<String> {
+    let (id, result) = match JsonValue::from_str(line) {
+        Ok(request) => {
+            let result = handle_request(&request);
+            let id = match request.get::<HashMap<String, JsonValue>>() {
+                Some(request) => request.get("id").cloned()?,
+                // requests that are not objects are invalid, their id cannot be known.
+                None => JsonValue::Null,
+            };
+            (id, result)
+        }
+        Err(_) => (
+            JsonValue::Null,
+            Err(RpcError::new(PARSE_ERROR, "request is not valid JSON")),
+        ),
+    };
+
+    let mut response: HashMap<String, JsonValue> = HashMap::new();
+    response.insert("jsonrpc".into(), JsonValue::String("2.0".into()));
+    response.insert("id".into(), id);
+
+    match result {
+        Ok(result) => response.insert("result".into(), result),
+        Err(e) => response.insert(
+            "error".into(),
+            object([
+                ("code", JsonValue::Number(f64::from(e.code))),
+                ("message", JsonValue::String(e.message)),
+            ]),
+        ),
+    };
+
+    Some(
+        JsonValue::Object(response)
+            .stringify()
+            .expect("responses are serializable"),
+    )
+}
+
+fn handle_request(request: &JsonValue) -> Result<JsonValue, RpcError> {
+    let request = request
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or_else(|| RpcError::new(INVALID_REQUEST, "request is not an object"))?;
+
+    let method = request
+        .get("method")
+        .and_then(|m| m.get::<String>())
+        .ok_or_else(|| RpcError::new(INVALID_REQUEST, "request has no method"))?;
+
+    let params = match request.get("params") {
+        None | Some(JsonValue::Null) => Params::new(),
+        Some(JsonValue::Object(params)) => params.clone(),
+        Some(_) => {
+            return Err(RpcError::new(
+                INVALID_PARAMS,
+                "params must be an object with named params",
+            ))
+        }
+    };
+
+    // an invalid config file fails the request, so it can be fixed without restarting the service.
+    config::load().map_err(|e| RpcError::new(SERVER_ERROR, e))?;
+
+    match method.as_str() {
+        "listDays" => Ok(list_days()),
+        "solve" => solve(&params, false),
+        "bench" => solve(&params, true),
+        "test" => test(&params),
+        method => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("unknown method `{method}`"),
+        )),
+    }
+}
+
+fn list_days() -> JsonValue {
+    JsonValue::Array(
+        registry::solutions()
+            .iter()
+            .map(|solution| {
+                object([
+                    ("day", number(solution.day.into_inner())),
+                    ("parts", part_numbers(solution)),
+                ])
+            })
+            .collect(),
+    )
+}
+
+fn solve(params: &Params, bench: bool) -> Result<JsonValue, RpcError> {
+    let solution = solution_param(params)?;
+    let parts = parts_param(params, solution)?;
+    let input = input_param(params, solution.day)?;
+
+    let results = parts
+        .into_iter()
+        .map(|(part, func)| {
+            let mut result = run(func, &input, bench);
+            if let JsonValue::Object(map) = &mut result {
+                map.insert("part".into(), number(part));
+            }
+            result
+        })
+        .collect();
+
+    Ok(object([
+        ("day", number(solution.day.into_inner())),
+        ("parts", JsonValue::Array(results)),
+    ]))
+}
+
+fn test(params: &Params) -> Result<JsonValue, RpcError> {
+    let solution = solution_param(params)?;
+    let parts = parts_param(params, solution)?;
+
+    let mut passed = true;
+    let mut results = vec![];
+
+    for input in inputs::list(solution.day) {
+        let content = input
+            .read()
+            .map_err(|e| RpcError::new(SERVER_ERROR, format!("input `{}`: {e}", input.name)))?;
+
+        for (part, func) in &parts {
+            let (answer, error, _) = call(*func, &content);
+            let expected = input.expected[usize::from(*part) - 1].as_ref();
+
+            let outcome = match Outcome::new(answer.clone(), expected) {
+                Outcome::Correct(_) => "correct",
+                Outcome::Wrong(..) => {
+                    passed = false;
+                    "wrong"
+                }
+                Outcome::Unchecked(_) => "unchecked",
+            };
+
+            let mut result = object([
+                ("input", JsonValue::String(input.name.clone())),
+                ("part", number(*part)),
+                ("answer", optional_string(answer)),
+                ("expected", optional_string(expected.cloned())),
+                ("outcome", JsonValue::String(outcome.into())),
+            ]);
+            if let (Some(error), JsonValue::Object(map)) = (error, &mut result) {
+                map.insert("error".into(), JsonValue::String(error));
+            }
+            results.push(result);
+        }
+    }
+
+    Ok(object([
+        ("day", number(solution.day.into_inner())),
+        ("passed", JsonValue::Boolean(passed)),
+        ("results", JsonValue::Array(results)),
+    ]))
+}
+
+/// Runs a part once, or benches it, and describes its answer, error and timing.
+fn run(func: PartFn, input: &str, bench: bool) -> JsonValue {
+    let (answer, error, duration) = call(func, input);
+
+    let (duration, samples) = if bench && error.is_none() {
+        runner::sample(func, input, &duration)
+    } else {
+        (duration, 1)
+    };
+
+    let mut result = object([
+        ("answer", optional_string(answer)),
+        ("nanos", number(duration.as_nanos())),
+        ("samples", number(samples)),
+    ]);
+
+    if let (Some(error), JsonValue::Object(map)) = (error, &mut result) {
+        map.insert("error".into(), JsonValue::String(error));
+    }
+
+    result
+}
+
+/// Calls a part and returns its answer, or an error if it returned `None` or panicked.
+fn call(func: PartFn, input: &str) -> (Option<String>, Option<String>, Duration) {
+    let timer = Instant::now();
+    let result = registry::catch_panic(|| func(input));
+    let duration = timer.elapsed();
+
+    match result {
+        Ok(Some(answer)) => (Some(answer), None, duration),
+        Ok(None) => (None, Some("no answer".into()), duration),
+        Err(message) => (None, Some(format!("panicked: {message}")), duration),
+    }
+}
+
+fn solution_param(params: &Params) -> Result<&'static Solution, RpcError> {
+    let day = match params.get("day") {
+        Some(JsonValue::Number(day)) if day.fract() == 0.0 && (1.0..=25.0).contains(day) =>
+        {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            Day::new(*day as u8)
+        }
+        Some(JsonValue::String(day)) => Day::from_str(day).ok(),
+        _ => None,
+    }
+    .ok_or_else(|| RpcError::new(INVALID_PARAMS, "`day` must be a day between 1 and 25"))?;
+
+    registry::get(day)
+        .ok_or_else(|| RpcError::new(INVALID_PARAMS, format!("day {day} has no solution")))
+}
+
+fn parts_param(params: &Params, solution: &Solution) -> Result<Vec<(u8, PartFn)>, RpcError> {
+    let part = match params.get("part") {
+        None | Some(JsonValue::Null) => None,
+        Some(JsonValue::Number(part)) if *part == 1.0 || *part == 2.0 => {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let part = *part as u8;
+            Some(part)
+        }
+        Some(_) => return Err(RpcError::new(INVALID_PARAMS, "`part` must be 1 or 2")),
+    };
+
+    solution
+        .select_parts(part)
+        .map_err(|e| RpcError::new(INVALID_PARAMS, e))
+}
+
+fn input_param(params: &Params, day: Day) -> Result<String, RpcError> {
+    match params.get("input") {
+        Some(JsonValue::String(input)) => Ok(input.clone()),
+        None | Some(JsonValue::Null) => encryption::read_to_string(&config::get().input_path(day))
+            .map_err(|e| RpcError::new(SERVER_ERROR, format!("could not read input: {e}"))),
+        Some(_) => Err(RpcError::new(INVALID_PARAMS, "`input` must be a string")),
+    }
+}
+
+fn part_numbers(solution: &Solution) -> JsonValue {
+    JsonValue::Array(solution.part_numbers().map(number).collect())
+}
+
+fn object<const N: usize>(entries: [(&str, JsonValue); N]) -> JsonValue {
+    JsonValue::Object(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn number(value: impl Into<u128>) -> JsonValue {
+    JsonValue::Number(value.into() as f64)
+}
+
+fn optional_string(value: Option<String>) -> JsonValue {
+    value.map_or(JsonValue::Null, JsonValue::String)
+}
+
+#[cfg(all(feature = "test_lib", feature = "solutions"))]
+mod tests {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    use tinyjson::JsonValue;
+
+    use super::{handle_line, INVALID_PARAMS, INVALID_REQUEST, METHOD_NOT_FOUND, PARSE_ERROR};
+    use crate::template::registry;
+
+    fn response(line: &str) -> HashMap<String, JsonValue> {
+        let response = handle_line(line).expect("request has an id");
+        JsonValue::from_str(&response)
+            .unwrap()
+            .get::<HashMap<String, JsonValue>>()
+            .unwrap()
+            .clone()
+    }
+
+    fn error_code(response: &HashMap<String, JsonValue>) -> f64 {
+        let error: &HashMap<String, JsonValue> = response["error"].get().unwrap();
+        *error["code"].get::<f64>().unwrap()
+    }
+
+    #[test]
+    fn reports_protocol_errors() {
+        assert_eq!(error_code(&response("{")), f64::from(PARSE_ERROR));
+        assert_eq!(
+            error_code(&response(r#"{"jsonrpc":"2.0","id":1,"method":"nope"}"#)),
+            f64::from(METHOD_NOT_FOUND)
+        );
+        assert_eq!(
+            error_code(&response(
+                r#"{"jsonrpc":"2.0","id":1,"method":"solve","params":{"day":26}}"#
+            )),
+            f64::from(INVALID_PARAMS)
+        );
+    }
+
+    #[test]
+    fn rejects_requests_that_are_not_objects() {
+        for line in ["5", "[]", "\"listDays\""] {
+            let response = response(line);
+            assert_eq!(error_code(&response), f64::from(INVALID_REQUEST));
+            assert_eq!(response["id"], JsonValue::Null);
+        }
+    }
+
+    #[test]
+    fn ignores_notifications() {
+        assert!(handle_line(r#"{"jsonrpc":"2.0","method":"listDays"}"#).is_none());
+    }
+
+    #[test]
+    fn lists_days() {
+        let response = response(r#"{"jsonrpc":"2.0","id":"a","method":"listDays"}"#);
+        assert_eq!(response["id"], JsonValue::String("a".into()));
+        let days: &Vec<JsonValue> = response["result"].get().unwrap();
+        assert_eq!(days.len(), registry::solutions().len());
+    }
+
+    #[test]
+    fn solves_given_inputs() {
+        registry::get(crate::day!(1)).expect("day 1 is registered");
+
+        let response = response(
+            r#"{"jsonrpc":"2.0","id":2,"method":"solve","params":{"day":1,"part":1,"input":"3 4\n4 3\n2 5\n1 3\n3 9\n3 3\n"}}"#,
+        );
+        let result: &HashMap<String, JsonValue> = response["result"].get().unwrap();
+        let parts: &Vec<JsonValue> = result["parts"].get().unwrap();
+        let part: &HashMap<String, JsonValue> = parts[0].get().unwrap();
+
+        assert_eq!(part["part"], JsonValue::Number(1.0));
+        assert_eq!(part["answer"], JsonValue::String("11".into()));
+        assert_eq!(part["samples"], JsonValue::Number(1.0));
+        assert!(part.get("error").is_none());
+    }
+}
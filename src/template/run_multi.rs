@@ -1,16 +1,23 @@
 use std::{collections::HashSet, io};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{config, external, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    all_inputs: bool,
+) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
+    let mut disagreeing_days = 0;
+    let mut failing_days = 0;
 
     // NOTE: use non-duplicate, sorted day values.
     all_days()
@@ -24,6 +31,18 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
+            // external solutions are only compared on the default input, see `commands::all`.
+            if all_inputs {
+                let (output, success) =
+                    child_commands::run_solution_on_all_inputs(day, is_release).unwrap();
+                if output.is_empty() {
+                    println!("Not solved.");
+                } else if !success {
+                    failing_days += 1;
+                }
+                return;
+            }
+
             let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
 
             if output.is_empty() {
@@ -32,8 +51,26 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
                 let val = child_commands::parse_exec_time(&output, day);
                 timings.push(val);
             }
+
+            if config::get().external_commands(day).next().is_some()
+                && !external::compare(day, &output)
+            {
+                disagreeing_days += 1;
+            }
         });
 
+    if failing_days > 0 {
+        println!(
+            "\n{ANSI_BOLD}Answers differ from the expected answers on {failing_days} day(s).{ANSI_RESET}"
+        );
+    }
+
+    if disagreeing_days > 0 {
+        println!(
+            "\n{ANSI_BOLD}External solutions disagree on {disagreeing_days} day(s).{ANSI_RESET}"
+        );
+    }
+
     if is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
@@ -59,32 +96,49 @@ impl From<std::io::Error> for Error {
     }
 }
 
+/// Link to the solution bin of a day, relative to the project root.
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    config::get().bin_link(day)
 }
 
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::Error;
+    use crate::template::{config, Day};
     use std::{
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        process::{Command, ExitStatus, Stdio},
+        sync::mpsc,
         thread,
     };
 
+    /// A single line written by a child command.
+    pub enum OutputLine {
+        Stdout(String),
+        Stderr(String),
+    }
+
     /// Run the solution bin for a given day
     pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+        run_solution_streamed(day, is_timed, is_release, print_line)
+    }
+
+    /// Run the solution bin for a given day, passing every line of output to `on_line` as soon as it is written.
+    pub fn run_solution_streamed(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        on_line: impl FnMut(&OutputLine),
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !config::get().bin_path(day).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = config::get().bin_name(day);
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
@@ -96,11 +150,71 @@ pub mod child_commands {
             args.push("--time");
         }
 
+        run_cargo(&args, on_line)
+    }
+
+    /// Run the solution bin for a given day on all of its inputs.
+    /// Returns whether all answers matched the expected answers along with the output.
+    pub fn run_solution_on_all_inputs(
+        day: Day,
+        is_release: bool,
+    ) -> Result<(Vec<String>, bool), Error> {
+        if !config::get().bin_path(day).exists() {
+            return Ok((vec![], true));
+        }
+
+        let bin_name = config::get().bin_name(day);
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
+
+        if is_release {
+            args.push("--release");
+        }
+
+        args.extend(["--", "--all-inputs"]);
+        let (output, status) = run_cargo_with_status(&args, print_line)?;
+        Ok((output, status.success()))
+    }
+
+    /// Run the unit tests of the solution bin for a given day and capture their output.
+    pub fn run_tests(day: Day) -> Result<Vec<String>, Error> {
+        run_tests_streamed(day, |_| {})
+    }
+
+    /// Run the unit tests of the solution bin for a given day, passing every line of output to `on_line`.
+    pub fn run_tests_streamed(
+        day: Day,
+        on_line: impl FnMut(&OutputLine),
+    ) -> Result<Vec<String>, Error> {
+        if !config::get().bin_path(day).exists() {
+            return Ok(vec![]);
+        }
+
+        run_cargo(&["test", "--bin", &config::get().bin_name(day)], on_line)
+    }
+
+    fn print_line(line: &OutputLine) {
+        match line {
+            OutputLine::Stdout(line) => println!("{line}"),
+            OutputLine::Stderr(line) => eprintln!("{line}"),
+        }
+    }
+
+    /// Spawn a cargo command and hand its output to `on_line` while grabbing stdout lines.
+    fn run_cargo(args: &[&str], on_line: impl FnMut(&OutputLine)) -> Result<Vec<String>, Error> {
+        run_cargo_with_status(args, on_line).map(|(output, _)| output)
+    }
+
+    /// Like [`run_cargo`], but also returns the exit status of the command.
+    fn run_cargo_with_status(
+        args: &[&str],
+        mut on_line: impl FnMut(&OutputLine),
+    ) -> Result<(Vec<String>, ExitStatus), Error> {
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // both pipes are drained on separate threads and funneled through a channel, so lines arrive in order of writing.
 
         let mut cmd = Command::new("cargo")
-            .args(&args)
+            .args(args)
+            .current_dir(&config::get().root)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -108,22 +222,61 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let (tx, rx) = mpsc::channel();
+        let stderr_tx = tx.clone();
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+        let stdout_thread = thread::spawn(move || {
+            stdout.lines().map_while(Result::ok).for_each(|line| {
+                let _ = tx.send(OutputLine::Stdout(line));
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let stderr_thread = thread::spawn(move || {
+            stderr.lines().map_while(Result::ok).for_each(|line| {
+                let _ = stderr_tx.send(OutputLine::Stderr(line));
+            });
+        });
+
+        let mut output = vec![];
+
+        for line in rx {
+            on_line(&line);
+            if let OutputLine::Stdout(line) = line {
+                output.push(line);
+            }
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        stdout_thread.join().unwrap();
+        stderr_thread.join().unwrap();
+        let status = cmd.wait()?;
+
+        Ok((output, status))
+    }
+
+    /// Run the unit tests of the solution bins of several days in a single cargo invocation.
+    /// Captures stdout and stderr in order of writing, so the output can be split by the `Running` line of each bin.
+    pub fn run_tests_of_days(days: &[Day]) -> Result<Vec<String>, Error> {
+        let config = config::get();
+        let bin_names: Vec<String> = days
+            .iter()
+            .filter(|day| config.bin_path(**day).exists())
+            .map(|day| config.bin_name(*day))
+            .collect();
+
+        if bin_names.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut args = vec!["test", "--no-fail-fast"];
+        for bin_name in &bin_names {
+            args.extend(["--bin", bin_name]);
+        }
+
+        let mut output = vec![];
+        run_cargo(&args, |line| {
+            let (OutputLine::Stdout(line) | OutputLine::Stderr(line)) = line;
+            output.push(line.clone());
+        })?;
 
         Ok(output)
     }
@@ -134,6 +287,7 @@ pub mod child_commands {
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
+            stale: false,
         };
 
         output
@@ -173,20 +327,25 @@ pub mod child_commands {
         let str_timing = line
             .split(" samples)")
             .next()?
-            .split('(')
-            .last()?
+            .rsplit('(')
+            .next()?
             .split('@')
             .next()?
             .trim();
 
-        let parsed_timing = match str_timing {
+        let parsed_timing = parse_nanos(str_timing)?;
+
+        Some((str_timing, parsed_timing))
+    }
+
+    /// Converts a formatted duration (e.g. `74.13µs`) to nanoseconds.
+    pub fn parse_nanos(str_timing: &str) -> Option<f64> {
+        match str_timing {
             s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
             s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
             s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
             s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        }
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -6,6 +6,14 @@ use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use crate::template::answers::Answers;
+use crate::template::config;
+use crate::template::encryption;
+use crate::template::fingerprints;
+pub use crate::template::fingerprints::check_input;
+use crate::template::inputs::{self, Outcome};
+use crate::template::puzzle;
+use crate::template::solve_times;
 use crate::template::ANSI_BOLD;
 use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
 
@@ -22,9 +30,75 @@ pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, da
     }
 }
 
+/// A solution part that maps an input to its answer.
+pub type PartFn<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// Runs every part on all inputs of a day and prints a table of the answers.
+/// Answers are compared against the expected answers of each input, exits with an error if any differs.
+pub fn run_all_inputs(day: Day, parts: &[(u8, PartFn)]) {
+    let inputs = inputs::list(day);
+
+    if inputs.is_empty() {
+        println!(
+            "No inputs found. Add inputs to \"{}\" or download the default input.",
+            inputs::folder(day).display()
+        );
+        process::exit(1);
+    }
+
+    let mut rows = vec![];
+
+    for input in &inputs {
+        let content = match input.read() {
+            Ok(content) => content,
+            Err(e) => {
+                eprintln!("Failed to read input \"{}\": {e}", input.name);
+                process::exit(1);
+            }
+        };
+
+        if input.name == inputs::DEFAULT_INPUT {
+            check_input(day, &content);
+        }
+
+        let outcomes = parts
+            .iter()
+            .map(|(part, func)| {
+                let expected = input.expected[usize::from(*part) - 1].as_ref();
+                Outcome::new(func(&content), expected)
+            })
+            .collect();
+
+        rows.push((input.name.clone(), outcomes));
+    }
+
+    let part_numbers: Vec<u8> = parts.iter().map(|(part, _)| *part).collect();
+    for (i, line) in inputs::format_table(&part_numbers, &rows)
+        .iter()
+        .enumerate()
+    {
+        if i == 0 {
+            println!("{ANSI_BOLD}{line}{ANSI_RESET}");
+        } else {
+            println!("{line}");
+        }
+    }
+
+    let wrong = rows
+        .iter()
+        .flat_map(|(_, outcomes)| outcomes)
+        .filter(|outcome| matches!(outcome, Outcome::Wrong(..)))
+        .count();
+
+    if wrong > 0 {
+        eprintln!("{wrong} answer(s) differ from the expected answers.");
+        process::exit(1);
+    }
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. in release, the function is benched (by default approx. 1 second of execution time or 10 samples, whatever take longer.)
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
@@ -56,8 +130,20 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    sample(func, input, base_time)
+}
+
+/// Runs a function repeatedly and returns its average duration and the number of samples.
+/// The number of samples depends on the duration of a first run and the bench settings.
+pub fn sample<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+) -> (Duration, u128) {
+    let settings = &config::get().bench;
+    let bench_iterations = (Duration::from_millis(settings.target_millis).as_nanos()
+        / cmp::max(base_time.as_nanos(), 10))
+    .clamp(settings.min_samples, settings.max_samples);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -161,5 +247,37 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let result = result.to_string();
+    let output = aoc_cli::submit(day, part, &result);
+
+    if output.as_ref().is_ok_and(aoc_cli::is_correct_answer) {
+        record_answer(day, part, &result);
+        solve_times::record_accepted(day, part);
+
+        // solving part one unlocks part two, fetch the updated description.
+        if part == 1 {
+            if let Err(e) = puzzle::refresh(day) {
+                eprintln!("Failed to update puzzle description: {e}");
+            }
+        }
+    }
+
+    Some(output)
+}
+
+/// Store an accepted answer in the answers file, along with the fingerprint of the input.
+fn record_answer(day: Day, part: u8, result: &str) {
+    let mut answers = Answers::read_from_file();
+
+    // the other part was answered for a different input if the input changed.
+    let input = encryption::read_to_string(&config::get().input_path(day)).unwrap_or_default();
+    if fingerprints::record(day, &input) {
+        answers.mark_stale(day);
+    }
+    answers.set(day, part, result);
+
+    match answers.store_file() {
+        Ok(()) => println!("Stored answer for part {part}."),
+        Err(e) => eprintln!("Failed to store answer: {e}"),
+    }
 }
@@ -0,0 +1,263 @@
+/// Module that measures how the parts of a solution scale with the size of their input.
+/// Inputs are produced by the generator of a day, see [`generator!`](crate::generator). The growth exponent `k`
+/// of a part, as in `O(n^k)`, is the slope of a least-squares fit of the logarithm of the run time over the
+/// logarithm of the size.
+use std::time::{Duration, Instant};
+
+use crate::template::differential::{Generator, Rng};
+use crate::template::registry::{self, PartFn};
+use crate::template::{config, runner};
+
+/// Default sizes double from here.
+pub const START_SIZE: usize = 8;
+/// Default sizes stop doubling after this many steps, even if the part stays fast.
+pub const MAX_STEPS: u32 = 16;
+
+/// Sizes whose generated input is predicted to be larger than this are skipped.
+pub const MAX_INPUT_BYTES: usize = 256 << 20;
+
+/// Each size is measured on inputs from this many seeds, the median run time is kept.
+/// Seeds count up from zero, so generated inputs are the same in every run.
+const SEEDS: u64 = 3;
+
+/// Run time of a part on a generated input of a size.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Measurement {
+    pub size: usize,
+    /// Length of the generated input in bytes.
+    pub bytes: usize,
+    /// Time the generator took to produce the input.
+    pub generation: Duration,
+    pub duration: Duration,
+    pub samples: u128,
+}
+
+/// Generates inputs of a size from several seeds and benches a part on each, keeping the median run time.
+/// Fails if the part panics or has no answer.
+pub fn measure(func: PartFn, generate: Generator, size: usize) -> Result<Measurement, String> {
+    let mut measurements = (0..SEEDS)
+        .map(|seed| measure_seed(func, generate, size, seed))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    measurements.sort_by_key(|measurement| measurement.duration);
+    Ok(measurements.swap_remove(measurements.len() / 2))
+}
+
+/// Generates the input of a seed and benches a part on it.
+/// Parts that take longer than the bench target for their samples are measured once.
+fn measure_seed(
+    func: PartFn,
+    generate: Generator,
+    size: usize,
+    seed: u64,
+) -> Result<Measurement, String> {
+    let timer = Instant::now();
+    let input = generate(&mut Rng::new(seed), size);
+    let generation = timer.elapsed();
+
+    let timer = Instant::now();
+    let answer =
+        registry::catch_panic(|| func(&input)).map_err(|message| format!("panicked: {message}"))?;
+    let base_time = timer.elapsed();
+
+    if answer.is_none() {
+        return Err("no answer".into());
+    }
+
+    let bench = &config::get().bench;
+    let (duration, samples) =
+        if base_time.as_millis() * bench.min_samples > u128::from(bench.target_millis) {
+            (base_time, 1)
+        } else {
+            runner::sample(func, input.as_str(), &base_time)
+        };
+
+    Ok(Measurement {
+        size,
+        bytes: input.len(),
+        generation,
+        duration,
+        samples,
+    })
+}
+
+/// Checks whether measuring a size would blow the time budget or the input size cap, by extrapolating the
+/// run time, generation time and input length of the last two measurements with their local exponents.
+/// Returns why the size should be skipped.
+pub fn over_budget(measurements: &[Measurement], size: usize, budget: Duration) -> Option<String> {
+    let [.., a, b] = measurements else {
+        return None;
+    };
+
+    let predict =
+        |value: fn(&Measurement) -> f64| extrapolate(a.size, value(a), b.size, value(b), size);
+
+    let duration = predict(|m| m.duration.as_secs_f64());
+    if duration > budget.as_secs_f64() {
+        return Some(format!(
+            "a run would take about {:.1?}",
+            Duration::from_secs_f64(duration)
+        ));
+    }
+
+    let generation = predict(|m| m.generation.as_secs_f64());
+    if generation > budget.as_secs_f64() {
+        return Some(format!(
+            "generating the input would take about {:.1?}",
+            Duration::from_secs_f64(generation)
+        ));
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let bytes = predict(|m| m.bytes as f64);
+    #[allow(clippy::cast_precision_loss)]
+    if bytes > MAX_INPUT_BYTES as f64 {
+        return Some(format!(
+            "the input would be about {} MiB",
+            (bytes / f64::from(1 << 20)).round()
+        ));
+    }
+
+    None
+}
+
+/// Extrapolates `value_b` from `size_b` to `size` with the growth exponent between the two points.
+/// Values that shrink are assumed to stay constant.
+#[allow(clippy::cast_precision_loss)]
+fn extrapolate(size_a: usize, value_a: f64, size_b: usize, value_b: f64, size: usize) -> f64 {
+    if size_a == 0 || size_b <= size_a || value_a <= 0.0 || value_b <= 0.0 {
+        return value_b;
+    }
+
+    let exponent = ((value_b / value_a).ln() / (size_b as f64 / size_a as f64).ln()).max(0.0);
+    value_b * (size as f64 / size_b as f64).powf(exponent)
+}
+
+/// Growth exponent between two measurements.
+pub fn local_exponent(a: &Measurement, b: &Measurement) -> Option<f64> {
+    fit_exponent(&[a.clone(), b.clone()])
+}
+
+/// Fits the growth exponent over the larger half of the measurements, where constant costs matter less.
+/// Returns the exponent along with the measurements it was fitted over.
+pub fn growth_exponent(measurements: &[Measurement]) -> Option<(f64, &[Measurement])> {
+    let start = (measurements.len() / 2).min(measurements.len().saturating_sub(2));
+    let fitted = &measurements[start..];
+    fit_exponent(fitted).map(|exponent| (exponent, fitted))
+}
+
+/// Slope of the least-squares line through `(ln size, ln duration)`.
+fn fit_exponent(measurements: &[Measurement]) -> Option<f64> {
+    #[allow(clippy::cast_precision_loss)]
+    let points: Vec<(f64, f64)> = measurements
+        .iter()
+        .filter(|m| m.size > 0 && !m.duration.is_zero())
+        .map(|m| ((m.size as f64).ln(), m.duration.as_secs_f64().ln()))
+        .collect();
+
+    if points.len() < 2 {
+        return None;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let covariance: f64 = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+
+    (variance > 0.0).then(|| covariance / variance)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{fit_exponent, growth_exponent, local_exponent, over_budget, Measurement};
+
+    fn measurement(size: usize, nanos: u64) -> Measurement {
+        Measurement {
+            size,
+            bytes: size,
+            generation: Duration::from_nanos(size as u64),
+            duration: Duration::from_nanos(nanos),
+            samples: 1,
+        }
+    }
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.unwrap();
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn fits_exponents() {
+        let quadratic: Vec<_> = [8, 16, 32, 64]
+            .iter()
+            .map(|&n| measurement(n, (n * n * 100) as u64))
+            .collect();
+        assert_close(fit_exponent(&quadratic), 2.0);
+        assert_close(local_exponent(&quadratic[0], &quadratic[1]), 2.0);
+
+        assert_eq!(fit_exponent(&quadratic[..1]), None);
+        assert_eq!(
+            fit_exponent(&[measurement(8, 100), measurement(8, 200)]),
+            None
+        );
+    }
+
+    #[test]
+    fn fits_growth_over_larger_sizes() {
+        // constant costs dominate the small sizes.
+        let measurements: Vec<_> = [8, 16, 32, 64, 128, 256]
+            .iter()
+            .map(|&n| measurement(n, 1_000_000 + (n * n * n * 10) as u64))
+            .collect();
+
+        let (exponent, fitted) = growth_exponent(&measurements).unwrap();
+        assert_eq!(fitted.first().unwrap().size, 64);
+        assert!((2.5..3.0).contains(&exponent), "{exponent}");
+
+        let (_, fitted) = growth_exponent(&measurements[..2]).unwrap();
+        assert_eq!(fitted.len(), 2);
+        assert_eq!(growth_exponent(&measurements[..1]), None);
+    }
+
+    #[test]
+    fn skips_sizes_over_budget() {
+        let budget = Duration::from_millis(100);
+        // quadratic, 10ms at size 16, so about 40ms at 32 and 160ms at 64.
+        let measurements = [measurement(8, 2_500_000), measurement(16, 10_000_000)];
+
+        assert_eq!(over_budget(&measurements[..1], 64, budget), None);
+        assert_eq!(over_budget(&measurements, 32, budget), None);
+        assert_eq!(
+            over_budget(&measurements, 64, budget).as_deref(),
+            Some("a run would take about 160.0ms")
+        );
+
+        let mut slow_generation = measurements.clone();
+        slow_generation[0].generation = Duration::from_millis(30);
+        slow_generation[1].generation = Duration::from_millis(60);
+        assert_eq!(
+            over_budget(&slow_generation, 32, budget).as_deref(),
+            Some("generating the input would take about 120.0ms")
+        );
+
+        let mut large = measurements.clone();
+        large[0].bytes = 16 << 20;
+        large[1].bytes = 64 << 20;
+        assert_eq!(over_budget(&large, 32, budget), None);
+        assert_eq!(
+            over_budget(&large, 64, Duration::from_secs(1)).as_deref(),
+            Some("the input would be about 1024 MiB")
+        );
+    }
+}
@@ -0,0 +1,674 @@
+/// Module that implements a small HTTP server exposing progress, timings and answers.
+/// It only depends on `std`: every connection is handled on its own thread and closed after one response.
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::answers::Answers;
+use crate::template::html_report::escape;
+use crate::template::run_multi::child_commands::{self, OutputLine};
+use crate::template::status::DayStatus;
+use crate::template::timings::Timings;
+use crate::template::{all_days, Day};
+
+/// Connections that are handled at the same time, further connections are turned away.
+const MAX_CONNECTIONS: usize = 16;
+/// Time a client may take to send its request or to receive the response.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2rem auto; max-width: 760px; } \
+    td, th { padding: 0.25rem 0.75rem; text-align: left; } pre { background: #f6f8fa; padding: 1rem; overflow-x: auto; }";
+
+/// State of the most recent run of a day that was triggered through the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RunState {
+    Running,
+    Finished,
+    Failed(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub state: RunState,
+    pub output: Vec<String>,
+}
+
+/// Runs shared between connection threads.
+pub type Runs = Arc<Mutex<HashMap<Day, Run>>>;
+
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub is_form: bool,
+    pub host: Option<String>,
+    pub origin: Option<String>,
+}
+
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub location: Option<String>,
+    pub body: String,
+}
+
+impl Response {
+    fn html(status: u16, body: String) -> Self {
+        Response {
+            status,
+            content_type: "text/html; charset=utf-8",
+            location: None,
+            body,
+        }
+    }
+
+    fn json(status: u16, value: JsonValue) -> Self {
+        Response {
+            status,
+            content_type: "application/json",
+            location: None,
+            body: value.stringify().unwrap_or_default(),
+        }
+    }
+
+    fn redirect(location: String) -> Self {
+        Response {
+            status: 303,
+            content_type: "text/plain; charset=utf-8",
+            location: Some(location),
+            body: String::new(),
+        }
+    }
+
+    fn not_found() -> Self {
+        Response::json(404, error_json("not found."))
+    }
+}
+
+/// Decrements the number of open connections when a connection thread ends.
+struct ConnectionGuard(Arc<AtomicUsize>);
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Listens on `addr` and serves requests until the process is stopped.
+pub fn serve(addr: &str) -> io::Result<()> {
+    let listener = TcpListener::bind(addr)?;
+    println!("🎄 Serving dashboard on http://{}/", listener.local_addr()?);
+    serve_listener(&listener, &Runs::default());
+    Ok(())
+}
+
+fn serve_listener(listener: &TcpListener, runs: &Runs) {
+    let Ok(addr) = listener.local_addr() else {
+        return;
+    };
+    let connections = Arc::new(AtomicUsize::new(0));
+
+    for stream in listener.incoming() {
+        let Ok(mut stream) = stream else {
+            continue;
+        };
+        if stream.set_read_timeout(Some(TIMEOUT)).is_err()
+            || stream.set_write_timeout(Some(TIMEOUT)).is_err()
+        {
+            continue;
+        }
+
+        if connections.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+            connections.fetch_sub(1, Ordering::SeqCst);
+            let busy = Response::json(503, error_json("too many connections."));
+            let _ = write_response(&mut stream, &busy);
+            continue;
+        }
+
+        let guard = ConnectionGuard(Arc::clone(&connections));
+        let runs = Arc::clone(runs);
+        thread::spawn(move || {
+            let _guard = guard;
+            if let Err(e) = handle_connection(stream, &runs, addr) {
+                eprintln!("Failed to handle request: {e}");
+            }
+        });
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, runs: &Runs, addr: SocketAddr) -> io::Result<()> {
+    let request = read_request(&mut BufReader::new(&stream))?;
+    let response = if request.method == "POST" && !is_same_origin(&request, addr) {
+        Response::json(403, error_json("cross-origin requests are not allowed."))
+    } else {
+        route(&request, runs)
+    };
+    write_response(&mut stream, &response)
+}
+
+/// Checks that a request was sent to the address the server is bound to from one of its own pages.
+/// Browsers send an `Origin` with cross-site form posts, a foreign `Host` points to DNS rebinding.
+fn is_same_origin(request: &Request, addr: SocketAddr) -> bool {
+    let Some(host) = &request.host else {
+        return false;
+    };
+
+    let is_bound_host = addr.ip().is_unspecified()
+        || *host == addr.to_string()
+        || (addr.ip().is_loopback() && *host == format!("localhost:{}", addr.port()));
+
+    is_bound_host
+        && request
+            .origin
+            .as_ref()
+            .is_none_or(|origin| origin.strip_prefix("http://") == Some(host.as_str()))
+}
+
+fn read_request(reader: &mut impl BufRead) -> io::Result<Request> {
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    let mut is_form = false;
+    let mut host = None;
+    let mut origin = None;
+
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.trim().parse().unwrap_or(0),
+                "content-type" => is_form = value.contains("application/x-www-form-urlencoded"),
+                "host" => host = Some(value.trim().to_string()),
+                "origin" => origin = Some(value.trim().to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    // request bodies are not used by any endpoint, but need to be drained before responding.
+    io::copy(&mut reader.take(content_length), &mut io::sink())?;
+
+    Ok(Request {
+        method,
+        path,
+        is_form,
+        host,
+        origin,
+    })
+}
+
+fn write_response(stream: &mut impl Write, response: &Response) -> io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        202 => "Accepted",
+        303 => "See Other",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+
+    let mut head = format!(
+        "HTTP/1.1 {} {reason}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.content_type,
+        response.body.len()
+    );
+    if let Some(location) = &response.location {
+        let _ = write!(head, "Location: {location}\r\n");
+    }
+    head.push_str("\r\n");
+
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(response.body.as_bytes())?;
+    stream.flush()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Maps a request to a response. Paths under `/api` return JSON, all others return HTML pages.
+pub fn route(request: &Request, runs: &Runs) -> Response {
+    let path = request.path.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", []) => Response::html(200, index_page(runs)),
+        ("GET", ["days", day]) => match Day::from_str(day) {
+            Ok(day) => Response::html(200, day_page(day, runs)),
+            Err(_) => Response::not_found(),
+        },
+        ("GET", ["api", "timings"]) => {
+            Response::json(200, JsonValue::from(Timings::read_from_file()))
+        }
+        ("GET", ["api", "answers"]) => {
+            Response::json(200, JsonValue::from(Answers::read_from_file()))
+        }
+        ("GET", ["api", "status"]) => Response::json(200, status_json(runs)),
+        ("GET", ["api", "days", day, "run"]) => match Day::from_str(day) {
+            Ok(day) => runs
+                .lock()
+                .unwrap()
+                .get(&day)
+                .map_or_else(Response::not_found, |run| {
+                    Response::json(200, run_json(day, run))
+                }),
+            Err(_) => Response::not_found(),
+        },
+        ("POST", ["api", "days", day, "run"]) => match Day::from_str(day) {
+            Ok(day) => {
+                let response = start_run(day, runs);
+                if request.is_form && response.status == 202 {
+                    Response::redirect(format!("/days/{day}"))
+                } else {
+                    response
+                }
+            }
+            Err(_) => Response::not_found(),
+        },
+        (_, ["api", "days", _, "run"]) => Response::json(405, error_json("method not allowed.")),
+        _ => Response::not_found(),
+    }
+}
+
+/// Starts a release run of a day in the background, unless one is still in progress.
+fn start_run(day: Day, runs: &Runs) -> Response {
+    {
+        let mut runs = runs.lock().unwrap();
+        if runs.get(&day).is_some_and(|r| r.state == RunState::Running) {
+            return Response::json(409, error_json("day is already running."));
+        }
+        runs.insert(
+            day,
+            Run {
+                state: RunState::Running,
+                output: vec![],
+            },
+        );
+    }
+
+    let thread_runs = Arc::clone(runs);
+    thread::spawn(move || {
+        let result = child_commands::run_solution_streamed(day, false, true, |line| {
+            let (OutputLine::Stdout(line) | OutputLine::Stderr(line)) = line;
+            if let Some(run) = thread_runs.lock().unwrap().get_mut(&day) {
+                run.output.push(line.clone());
+            }
+        });
+
+        if let Some(run) = thread_runs.lock().unwrap().get_mut(&day) {
+            run.state = match result {
+                Ok(_) => RunState::Finished,
+                Err(e) => RunState::Failed(format!("{e:?}")),
+            };
+        }
+    });
+
+    let run = runs.lock().unwrap().get(&day).cloned().unwrap();
+    Response::json(202, run_json(day, &run))
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn error_json(message: &str) -> JsonValue {
+    JsonValue::Object(HashMap::from([(
+        "error".to_string(),
+        JsonValue::String(message.into()),
+    )]))
+}
+
+fn run_json(day: Day, run: &Run) -> JsonValue {
+    let (state, error) = match &run.state {
+        RunState::Running => ("running", JsonValue::Null),
+        RunState::Finished => ("finished", JsonValue::Null),
+        RunState::Failed(e) => ("failed", JsonValue::String(e.clone())),
+    };
+
+    JsonValue::Object(HashMap::from([
+        ("day".to_string(), JsonValue::String(day.to_string())),
+        ("state".to_string(), JsonValue::String(state.into())),
+        ("error".to_string(), error),
+        (
+            "output".to_string(),
+            JsonValue::Array(
+                run.output
+                    .iter()
+                    .map(|l| JsonValue::String(l.clone()))
+                    .collect(),
+            ),
+        ),
+    ]))
+}
+
+fn status_json(runs: &Runs) -> JsonValue {
+    let timings = Timings::read_from_file();
+    let answers = Answers::read_from_file();
+    let runs = runs.lock().unwrap();
+
+    let days = all_days()
+        .map(|day| {
+            let status = DayStatus::collect(day, &timings, &answers);
+            let parts = status
+                .parts
+                .iter()
+                .enumerate()
+                .map(|(i, part)| {
+                    let part_number = u8::try_from(i + 1).unwrap();
+                    JsonValue::Object(HashMap::from([
+                        (
+                            "answer".to_string(),
+                            answers
+                                .get(day, part_number)
+                                .map_or(JsonValue::Null, |a| JsonValue::String(a.into())),
+                        ),
+                        ("benched".to_string(), JsonValue::Boolean(part.benched)),
+                    ]))
+                })
+                .collect();
+
+            JsonValue::Object(HashMap::from([
+                ("day".to_string(), JsonValue::String(day.to_string())),
+                (
+                    "scaffolded".to_string(),
+                    JsonValue::Boolean(status.scaffolded),
+                ),
+                ("parts".to_string(), JsonValue::Array(parts)),
+                (
+                    "run".to_string(),
+                    runs.get(&day)
+                        .map_or(JsonValue::Null, |run| run_json(day, run)),
+                ),
+            ]))
+        })
+        .collect();
+
+    JsonValue::Object(HashMap::from([(
+        "data".to_string(),
+        JsonValue::Array(days),
+    )]))
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn page(title: &str, body: &str, refresh: bool) -> String {
+    let refresh = if refresh {
+        "<meta http-equiv=\"refresh\" content=\"2\">\n"
+    } else {
+        ""
+    };
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n{refresh}<title>{title}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n{body}</body>\n</html>\n"
+    )
+}
+
+fn index_page(runs: &Runs) -> String {
+    let timings = Timings::read_from_file();
+    let answers = Answers::read_from_file();
+    let runs = runs.lock().unwrap();
+
+    let mut body = String::from(
+        "<h1>Advent of Code</h1>\n<table>\n<tr><th>Day</th><th>Part 1</th><th>Part 2</th><th>Total</th><th>Run</th></tr>\n",
+    );
+
+    for day in all_days() {
+        let status = DayStatus::collect(day, &timings, &answers);
+        if !status.scaffolded {
+            continue;
+        }
+        let total = timings.data.iter().find(|t| t.day == day).map_or_else(
+            || "-".into(),
+            |t| format!("{:.2}ms", t.total_nanos / 1_000_000_f64),
+        );
+        let run = runs.get(&day).map_or("-", |run| match run.state {
+            RunState::Running => "running",
+            RunState::Finished => "finished",
+            RunState::Failed(_) => "failed",
+        });
+
+        let _ = writeln!(
+            body,
+            "<tr><td><a href=\"/days/{day}\">Day {day}</a></td><td>{}</td><td>{}</td><td>{total}</td><td>{run}</td></tr>",
+            status.parts[0].star(),
+            status.parts[1].star(),
+        );
+    }
+
+    body.push_str("</table>\n<p>★ answered &amp; benched · ☆ solved · · open</p>\n");
+    page("Advent of Code", &body, false)
+}
+
+fn day_page(day: Day, runs: &Runs) -> String {
+    let timings = Timings::read_from_file();
+    let answers = Answers::read_from_file();
+    let timing = timings.data.iter().find(|t| t.day == day);
+    let run = runs.lock().unwrap().get(&day).cloned();
+
+    let mut body = format!(
+        "<p><a href=\"/\">← All days</a></p>\n<h1>Day {day}</h1>\n<table>\n<tr><th></th><th>Answer</th><th>Time</th></tr>\n"
+    );
+
+    for part in 1..=2 {
+        let time = timing.and_then(|t| {
+            if part == 1 {
+                t.part_1.clone()
+            } else {
+                t.part_2.clone()
+            }
+        });
+        let _ = writeln!(
+            body,
+            "<tr><th>Part {part}</th><td>{}</td><td>{}</td></tr>",
+            escape(answers.get(day, part).unwrap_or("-")),
+            escape(time.as_deref().unwrap_or("-"))
+        );
+    }
+
+    let _ = writeln!(
+        body,
+        "</table>\n<form method=\"post\" action=\"/api/days/{day}/run\"><button>Run day {day}</button></form>"
+    );
+
+    let is_running = run.as_ref().is_some_and(|r| r.state == RunState::Running);
+
+    if let Some(run) = run {
+        let state = match &run.state {
+            RunState::Running => "Running…".to_string(),
+            RunState::Finished => "Finished.".to_string(),
+            RunState::Failed(e) => format!("Failed: {e}"),
+        };
+        let _ = writeln!(
+            body,
+            "<h2>Last run</h2>\n<p>{}</p>\n<pre>{}</pre>",
+            escape(&state),
+            escape(&run.output.join("\n"))
+        );
+    }
+
+    page(&format!("Day {day}"), &body, is_running)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        sync::Arc,
+        thread,
+    };
+
+    use super::{is_same_origin, route, serve_listener, Request, Run, RunState, Runs};
+    use crate::day;
+
+    fn get(path: &str) -> Request {
+        Request {
+            method: "GET".into(),
+            path: path.into(),
+            is_form: false,
+            host: Some("127.0.0.1:3000".into()),
+            origin: None,
+        }
+    }
+
+    fn post(path: &str, host: &str, origin: Option<&str>) -> Request {
+        Request {
+            method: "POST".into(),
+            path: path.into(),
+            is_form: true,
+            host: Some(host.into()),
+            origin: origin.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn serves_pages_and_json() {
+        let runs = Runs::default();
+        let index = route(&get("/"), &runs);
+        assert_eq!(index.status, 200);
+        assert!(index.content_type.starts_with("text/html"));
+
+        let timings = route(&get("/api/timings"), &runs);
+        assert_eq!(timings.status, 200);
+        assert_eq!(timings.content_type, "application/json");
+        assert!(timings.body.contains("\"data\""));
+
+        let status = route(&get("/api/status?pretty"), &runs);
+        assert_eq!(status.status, 200);
+        assert_eq!(status.body.matches("\"scaffolded\"").count(), 25);
+    }
+
+    #[test]
+    fn rejects_unknown_routes() {
+        let runs = Runs::default();
+        assert_eq!(route(&get("/nope"), &runs).status, 404);
+        assert_eq!(route(&get("/days/26"), &runs).status, 404);
+        assert_eq!(route(&get("/api/days/01/run"), &runs).status, 404);
+        assert_eq!(route(&get("/api/days/xx/run"), &runs).status, 404);
+
+        let delete = Request {
+            method: "DELETE".into(),
+            ..get("/api/days/01/run")
+        };
+        assert_eq!(route(&delete, &runs).status, 405);
+    }
+
+    #[test]
+    fn reports_runs() {
+        let runs = Runs::default();
+        runs.lock().unwrap().insert(
+            day!(3),
+            Run {
+                state: RunState::Running,
+                output: vec!["Part 1: <42>".into()],
+            },
+        );
+
+        let run = route(&get("/api/days/03/run"), &runs);
+        assert_eq!(run.status, 200);
+        assert!(run.body.contains("\"state\":\"running\""));
+
+        let post = Request {
+            is_form: false,
+            ..post("/api/days/03/run", "127.0.0.1:3000", None)
+        };
+        assert_eq!(route(&post, &runs).status, 409);
+
+        let page = route(&get("/days/03"), &runs);
+        assert!(page.body.contains("Part 1: &lt;42&gt;"));
+        assert!(page.body.contains("http-equiv=\"refresh\""));
+    }
+
+    #[test]
+    fn answers_plain_http_requests() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let runs = Runs::default();
+        let server_runs = Arc::clone(&runs);
+        thread::spawn(move || serve_listener(&listener, &server_runs));
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .write_all(b"GET /api/answers HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Type: application/json\r\n"));
+        assert!(response.contains("\r\n\r\n{\"data\":"));
+    }
+
+    #[test]
+    fn accepts_posts_of_own_pages_only() {
+        let addr = "127.0.0.1:3000".parse().unwrap();
+        let origin = Some("http://127.0.0.1:3000");
+        assert!(is_same_origin(&post("/", "127.0.0.1:3000", origin), addr));
+        assert!(is_same_origin(&post("/", "127.0.0.1:3000", None), addr));
+        assert!(is_same_origin(
+            &post("/", "localhost:3000", Some("http://localhost:3000")),
+            addr
+        ));
+        assert!(!is_same_origin(
+            &post("/", "127.0.0.1:3000", Some("https://example.com")),
+            addr
+        ));
+        assert!(!is_same_origin(&post("/", "example.com:3000", None), addr));
+        assert!(!is_same_origin(
+            &Request {
+                host: None,
+                ..post("/", "", None)
+            },
+            addr
+        ));
+
+        let shared = "0.0.0.0:3000".parse().unwrap();
+        let origin = Some("http://192.168.0.2:3000");
+        assert!(is_same_origin(
+            &post("/", "192.168.0.2:3000", origin),
+            shared
+        ));
+        assert!(!is_same_origin(
+            &post("/", "192.168.0.2:3000", Some("http://example.com")),
+            shared
+        ));
+    }
+
+    #[test]
+    fn rejects_cross_origin_posts() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let runs = Runs::default();
+        let server_runs = Arc::clone(&runs);
+        thread::spawn(move || serve_listener(&listener, &server_runs));
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        let request = format!(
+            "POST /api/days/01/run HTTP/1.1\r\nHost: {addr}\r\nOrigin: https://example.com\r\nContent-Length: 0\r\n\r\n"
+        );
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 403 Forbidden\r\n"));
+        assert!(runs.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,108 @@
+/// Module with helpers for timestamps and durations in the timezone of the Advent of Code server (UTC-5),
+/// where puzzles unlock at midnight. Dates are computed without a date library, so these helpers work without the
+/// `today` feature.
+use crate::template::Day;
+
+/// Offset of the server timezone from UTC in hours.
+pub const SERVER_UTC_OFFSET: i32 = -5;
+
+fn offset_seconds() -> i64 {
+    i64::from(SERVER_UTC_OFFSET) * 3600
+}
+
+/// Formats a number of seconds, e.g. `1h 02m 03s`.
+pub fn format_duration(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    let (days, hours, minutes, seconds) = (
+        seconds / 86400,
+        seconds % 86400 / 3600,
+        seconds % 3600 / 60,
+        seconds % 60,
+    );
+
+    if days > 0 {
+        format!("{sign}{days}d {hours:02}h {minutes:02}m")
+    } else if hours > 0 {
+        format!("{sign}{hours}h {minutes:02}m {seconds:02}s")
+    } else if minutes > 0 {
+        format!("{sign}{minutes}m {seconds:02}s")
+    } else {
+        format!("{sign}{seconds}s")
+    }
+}
+
+/// Unix timestamp at which the puzzle of a day unlocks.
+pub fn unlock_timestamp(year: i64, day: Day) -> i64 {
+    days_from_civil(year, 12, i64::from(day.into_inner())) * 86400 - offset_seconds()
+}
+
+/// Formats a unix timestamp as date and time in the server timezone.
+pub fn format_server_time(ts: i64) -> String {
+    let local = ts + offset_seconds();
+    let (year, month, day) = civil_from_days(local.div_euclid(86400));
+    let seconds = local.rem_euclid(86400);
+    format!(
+        "{year}-{month:02}-{day:02} {:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+/// Days since the unix epoch for a date of the proleptic gregorian calendar.
+/// See <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Inverse of [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        civil_from_days, days_from_civil, format_duration, format_server_time, unlock_timestamp,
+    };
+    use crate::day;
+
+    #[test]
+    fn converts_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2024, 12, 1), 20058);
+        assert_eq!(civil_from_days(20058), (2024, 12, 1));
+        assert_eq!(civil_from_days(days_from_civil(2000, 2, 29)), (2000, 2, 29));
+        assert_eq!(format_server_time(1733029200), "2024-12-01 00:00:00");
+        assert_eq!(format_server_time(1733029199), "2024-11-30 23:59:59");
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(42), "42s");
+        assert_eq!(format_duration(300), "5m 00s");
+        assert_eq!(format_duration(3723), "1h 02m 03s");
+        assert_eq!(format_duration(90000), "1d 01h 00m");
+    }
+
+    #[test]
+    fn computes_unlock_timestamps() {
+        assert_eq!(unlock_timestamp(2024, day!(1)), 1733029200);
+    }
+}
@@ -0,0 +1,528 @@
+/// Module that minimizes inputs on which a solution panics or disagrees with an external solution.
+/// Inputs are reduced with delta debugging, first over lines and, for grids, over columns and cells.
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::env;
+use std::io::{Read, Write};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tinyjson::JsonValue;
+
+use crate::template::config::{self, ExternalCommand};
+use crate::template::external;
+use crate::template::registry::PartFn;
+use crate::template::Day;
+
+thread_local! {
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// How a part behaves on an input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Behavior {
+    Answer(Option<String>),
+    /// The part panicked at a source location.
+    Panic(String),
+    Timeout,
+}
+
+/// What makes an input interesting, i.e. worth keeping while shrinking.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Oracle {
+    /// The part panics at the same location as on the original input.
+    Panic(String),
+    /// The part answers differently than an external solution.
+    Disagreement(ExternalCommand),
+}
+
+impl Oracle {
+    pub fn describe(&self) -> String {
+        match self {
+            Oracle::Panic(location) => format!("panics at {location}"),
+            Oracle::Disagreement(external) => format!("disagrees with `{}`", external.name),
+        }
+    }
+}
+
+/// Replaces the panic hook with one that records the location of a panic instead of printing it.
+/// The child processes of [`observe`] report the location to the shrinking process.
+pub fn capture_panics() {
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().map(|location| {
+            format!(
+                "{}:{}:{}",
+                solution_file(location.file()),
+                location.line(),
+                location.column()
+            )
+        });
+        PANIC_LOCATION.with(|cell| *cell.borrow_mut() = location);
+    }));
+}
+
+/// Solutions are compiled from copies in the build directory, e.g. `day_05.rs`. Maps them back to their solution file.
+fn solution_file(file: &str) -> String {
+    Path::new(file)
+        .file_name()
+        .and_then(|name| name.to_str()?.strip_prefix("day_")?.strip_suffix(".rs"))
+        .and_then(|day| Day::from_str(day).ok())
+        .map_or_else(|| file.to_string(), |day| config::get().bin_link(day))
+}
+
+/// Hidden command that runs a part in a child process, see [`observe`].
+pub const RUN_COMMAND: &str = "shrink-run";
+
+/// How often [`observe`] checks whether its child process exited.
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Runs a part in the current process and catches its panic. Call [`capture_panics`] first to record panic locations.
+pub fn evaluate(func: PartFn, input: &str) -> Behavior {
+    match panic::catch_unwind(|| func(input)) {
+        Ok(answer) => Behavior::Answer(answer),
+        Err(_) => Behavior::Panic(
+            PANIC_LOCATION
+                .with(|cell| cell.borrow_mut().take())
+                .unwrap_or_else(|| "an unknown location".into()),
+        ),
+    }
+}
+
+/// Runs a part on an input in a child process that runs [`RUN_COMMAND`]. Parts that run longer than `timeout` are
+/// killed, so candidates that send a solution into an endless loop do not keep running while shrinking continues.
+/// Child processes that exit without a result, e.g. after a stack overflow, count as a panic.
+pub fn observe(day: Day, part: u8, input: &str, timeout: Duration) -> Result<Behavior, String> {
+    let mut child = Command::new(env::current_exe().map_err(|e| e.to_string())?)
+        .args([RUN_COMMAND, &day.to_string(), &part.to_string()])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("failed to run part {part}: {e}"))?;
+
+    let mut stdin = child.stdin.take().ok_or("missing stdin")?;
+    let mut stdout = child.stdout.take().ok_or("missing stdout")?;
+
+    // both pipes are served on threads, so a large input or output cannot block the child.
+    let input = input.to_string();
+    let writer = thread::spawn(move || {
+        let _ = stdin.write_all(input.as_bytes());
+    });
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        let _ = stdout.read_to_string(&mut output);
+        output
+    });
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            break Some(status);
+        }
+        if started.elapsed() >= timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            break None;
+        }
+        thread::sleep(POLL_INTERVAL.min(timeout / 10));
+    };
+
+    let _ = writer.join();
+    let output = reader.join().unwrap_or_default();
+
+    let Some(status) = status else {
+        return Ok(Behavior::Timeout);
+    };
+
+    // solutions may print to stdout themselves, the result is printed last.
+    Ok(output
+        .lines()
+        .last()
+        .and_then(decode)
+        .unwrap_or_else(|| Behavior::Panic(format!("a crash ({status})"))))
+}
+
+/// Encodes a behavior as a single line of JSON for the parent process.
+pub fn encode(behavior: &Behavior) -> String {
+    let (key, value) = match behavior {
+        Behavior::Answer(answer) => (
+            "answer",
+            answer.clone().map_or(JsonValue::Null, JsonValue::String),
+        ),
+        Behavior::Panic(location) => ("panic", JsonValue::String(location.clone())),
+        Behavior::Timeout => ("timeout", JsonValue::Null),
+    };
+
+    JsonValue::Object(HashMap::from([(key.to_string(), value)]))
+        .stringify()
+        .expect("behaviors are serializable")
+}
+
+fn decode(line: &str) -> Option<Behavior> {
+    let json = JsonValue::from_str(line).ok()?;
+    let object = json.get::<HashMap<String, JsonValue>>()?;
+
+    match object.iter().next()? {
+        (key, JsonValue::String(answer)) if key == "answer" => {
+            Some(Behavior::Answer(Some(answer.clone())))
+        }
+        (key, JsonValue::Null) if key == "answer" => Some(Behavior::Answer(None)),
+        (key, JsonValue::String(location)) if key == "panic" => {
+            Some(Behavior::Panic(location.clone()))
+        }
+        (key, _) if key == "timeout" => Some(Behavior::Timeout),
+        _ => None,
+    }
+}
+
+/// Decides whether candidate inputs are interesting for a part, caching the decisions.
+pub struct Checker {
+    day: Day,
+    part: u8,
+    oracle: Oracle,
+    timeout: Duration,
+    /// External solutions read the candidate from this file.
+    candidate_path: PathBuf,
+    cache: HashMap<String, bool>,
+}
+
+impl Checker {
+    pub fn new(day: Day, part: u8, oracle: Oracle, timeout: Duration) -> Self {
+        Checker {
+            day,
+            part,
+            oracle,
+            timeout,
+            candidate_path: std::env::temp_dir().join(format!("aoc-shrink-{day}.txt")),
+            cache: HashMap::new(),
+        }
+    }
+
+    pub fn oracle(&self) -> &Oracle {
+        &self.oracle
+    }
+
+    /// Number of distinct inputs that were checked.
+    pub fn checks(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_interesting(&mut self, input: &str) -> bool {
+        if let Some(interesting) = self.cache.get(input) {
+            return *interesting;
+        }
+
+        let Ok(behavior) = observe(self.day, self.part, input, self.timeout) else {
+            return false;
+        };
+        let interesting = match &self.oracle {
+            Oracle::Panic(location) => behavior == Behavior::Panic(location.clone()),
+            Oracle::Disagreement(external) => {
+                behavior != Behavior::Timeout && self.disagrees(external, input, &behavior)
+            }
+        };
+
+        self.cache.insert(input.to_string(), interesting);
+        interesting
+    }
+
+    /// An input only counts as a disagreement if the external solution answers it.
+    fn disagrees(&self, external: &ExternalCommand, input: &str, behavior: &Behavior) -> bool {
+        if std::fs::write(&self.candidate_path, input).is_err() {
+            return false;
+        }
+
+        let run = external::run(external, &self.candidate_path, input);
+        match (&run.answers[usize::from(self.part) - 1], behavior) {
+            (Some(expected), Behavior::Answer(answer)) => answer.as_ref() != Some(expected),
+            (Some(_), Behavior::Panic(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Finds out why an input is interesting for a part, given how the part behaves on it. Without an external solution
+/// given by name, a panic is preferred over a disagreement with any of the external solutions of the day.
+pub fn find_oracle(
+    day: Day,
+    part: u8,
+    input: &str,
+    behavior: Behavior,
+    against: Option<&str>,
+    timeout: Duration,
+) -> Result<Oracle, String> {
+    let is_interesting =
+        |oracle: &Oracle| Checker::new(day, part, oracle.clone(), timeout).is_interesting(input);
+    let mut externals = config::get().external_commands(day);
+
+    if let Some(name) = against {
+        let external = externals
+            .find(|external| external.name == name)
+            .ok_or_else(|| format!("day {day} has no external solution named `{name}`"))?;
+        let oracle = Oracle::Disagreement(external.clone());
+        return if is_interesting(&oracle) {
+            Ok(oracle)
+        } else {
+            Err(format!("part {part} agrees with `{name}` on the input"))
+        };
+    }
+
+    match behavior {
+        Behavior::Panic(location) => Ok(Oracle::Panic(location)),
+        Behavior::Timeout => Err(format!(
+            "part {part} did not finish within {timeout:.1?} on the input"
+        )),
+        Behavior::Answer(_) => externals
+            .map(|external| Oracle::Disagreement(external.clone()))
+            .find(is_interesting)
+            .ok_or_else(|| {
+                format!("part {part} neither panics nor disagrees with an external solution on the input")
+            }),
+    }
+}
+
+/// Reduces `items` to a subsequence for which `test` still holds, such that removing any single item makes it fail.
+/// `test` is expected to hold for `items`.
+pub fn ddmin<T: Clone>(mut items: Vec<T>, mut test: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut granularity = 2;
+
+    while items.len() >= 2 {
+        let chunk_size = items.len().div_ceil(granularity);
+        let chunks: Vec<(usize, usize)> = (0..items.len())
+            .step_by(chunk_size)
+            .map(|start| (start, (start + chunk_size).min(items.len())))
+            .collect();
+
+        let subset = chunks
+            .iter()
+            .map(|&(start, end)| items[start..end].to_vec())
+            .find(|subset| test(subset));
+
+        if let Some(subset) = subset {
+            items = subset;
+            granularity = 2;
+            continue;
+        }
+
+        let complement = chunks
+            .iter()
+            .map(|&(start, end)| [&items[..start], &items[end..]].concat())
+            .find(|complement| test(complement));
+
+        if let Some(complement) = complement {
+            items = complement;
+            granularity = (granularity - 1).max(2);
+            continue;
+        }
+
+        if granularity >= items.len() {
+            break;
+        }
+        granularity = (granularity * 2).min(items.len());
+    }
+
+    items
+}
+
+/// A phase of shrinking, reported after it finished.
+pub struct Phase {
+    pub name: &'static str,
+    pub before: usize,
+    pub after: usize,
+}
+
+/// Shrinks an input over lines. Grids are then shrunk over columns, and their cells are replaced with the
+/// most common character one by one.
+pub fn shrink(
+    input: &str,
+    mut is_interesting: impl FnMut(&str) -> bool,
+    mut on_phase: impl FnMut(&Phase),
+) -> String {
+    let trailing_newline = input.ends_with('\n');
+    let join = |lines: &[String]| {
+        let mut joined = lines.join("\n");
+        if trailing_newline && !joined.is_empty() {
+            joined.push('\n');
+        }
+        joined
+    };
+
+    let lines: Vec<String> = input.lines().map(String::from).collect();
+    let before = lines.len();
+    let lines = ddmin(lines, |lines| is_interesting(&join(lines)));
+    on_phase(&Phase {
+        name: "lines",
+        before,
+        after: lines.len(),
+    });
+
+    let Some(grid) = Grid::parse(&lines) else {
+        return join(&lines);
+    };
+
+    let width = grid.width();
+    let columns = ddmin((0..width).collect(), |columns| {
+        is_interesting(&join(&grid.with_columns(columns).to_lines()))
+    });
+    let grid = grid.with_columns(&columns);
+    on_phase(&Phase {
+        name: "columns",
+        before: width,
+        after: columns.len(),
+    });
+
+    let background = grid.most_common();
+    let cells = grid.cells_except(background);
+    let before = cells.len();
+    let cells = ddmin(cells, |cells| {
+        is_interesting(&join(&grid.with_cells(cells, background).to_lines()))
+    });
+    on_phase(&Phase {
+        name: "cells",
+        before,
+        after: cells.len(),
+    });
+
+    join(&grid.with_cells(&cells, background).to_lines())
+}
+
+/// A rectangular input of at least two rows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Grid {
+    rows: Vec<Vec<char>>,
+}
+
+impl Grid {
+    fn parse(lines: &[String]) -> Option<Self> {
+        let rows: Vec<Vec<char>> = lines.iter().map(|line| line.chars().collect()).collect();
+        let width = rows.first()?.len();
+        (rows.len() >= 2 && width > 0 && rows.iter().all(|row| row.len() == width))
+            .then_some(Grid { rows })
+    }
+
+    fn width(&self) -> usize {
+        self.rows[0].len()
+    }
+
+    fn with_columns(&self, columns: &[usize]) -> Grid {
+        Grid {
+            rows: self
+                .rows
+                .iter()
+                .map(|row| columns.iter().map(|&x| row[x]).collect())
+                .collect(),
+        }
+    }
+
+    fn most_common(&self) -> char {
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        for &c in self.rows.iter().flatten() {
+            *counts.entry(c).or_default() += 1;
+        }
+        counts
+            .into_iter()
+            .max_by_key(|&(c, count)| (count, std::cmp::Reverse(c)))
+            .map_or('.', |(c, _)| c)
+    }
+
+    /// Positions of the cells that differ from `background`, row by row.
+    fn cells_except(&self, background: char) -> Vec<(usize, usize)> {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(move |(_, &c)| c != background)
+                    .map(move |(x, _)| (y, x))
+            })
+            .collect()
+    }
+
+    /// Keeps the given cells and replaces every other cell with `background`.
+    fn with_cells(&self, cells: &[(usize, usize)], background: char) -> Grid {
+        let mut rows = vec![vec![background; self.width()]; self.rows.len()];
+        for &(y, x) in cells {
+            rows[y][x] = self.rows[y][x];
+        }
+        Grid { rows }
+    }
+
+    fn to_lines(&self) -> Vec<String> {
+        self.rows.iter().map(|row| row.iter().collect()).collect()
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ddmin, decode, encode, evaluate, shrink, Behavior};
+
+    #[test]
+    fn finds_minimal_subsets() {
+        let items: Vec<u32> = (0..40).collect();
+        assert_eq!(
+            ddmin(items.clone(), |s| s.contains(&3) && s.contains(&37)),
+            vec![3, 37]
+        );
+
+        // the result is 1-minimal, not necessarily the smallest subset.
+        let test = |s: &[u32]| s.iter().sum::<u32>() >= 39;
+        let minimal = ddmin(items, test);
+        assert!(test(&minimal));
+        assert!((0..minimal.len()).all(|i| !test(&[&minimal[..i], &minimal[i + 1..]].concat())));
+    }
+
+    #[test]
+    fn shrinks_lines() {
+        let input = "1\n2\n3\nboom\n5\n6\n";
+        assert_eq!(shrink(input, |s| s.contains("boom"), |_| {}), "boom\n");
+
+        let input = "a\nb\nc";
+        assert_eq!(
+            shrink(input, |s| s.contains('a') && s.contains('c'), |_| {}),
+            "a\nc"
+        );
+    }
+
+    #[test]
+    fn shrinks_grids() {
+        let input = "#.....\n......\n..S...\n......\n....E.\n";
+        let interesting = |s: &str| s.lines().count() >= 2 && s.contains('S') && s.contains('E');
+
+        let mut phases = vec![];
+        let result = shrink(input, interesting, |phase| phases.push(phase.name));
+
+        assert_eq!(result, "S.\n.E\n");
+        assert_eq!(phases, vec!["lines", "columns", "cells"]);
+    }
+
+    #[test]
+    fn evaluates_panics() {
+        fn part(input: &str) -> Option<String> {
+            assert!(!input.is_empty(), "empty input");
+            Some(input.len().to_string())
+        }
+
+        assert_eq!(evaluate(part, "abc"), Behavior::Answer(Some("3".into())));
+        assert!(matches!(evaluate(part, ""), Behavior::Panic(_)));
+    }
+
+    #[test]
+    fn encodes_behaviors() {
+        for behavior in [
+            Behavior::Answer(Some("42\n7".into())),
+            Behavior::Answer(None),
+            Behavior::Panic("./src/bin/05.rs:12:5".into()),
+            Behavior::Timeout,
+        ] {
+            let line = encode(&behavior);
+            assert_eq!(line.lines().count(), 1);
+            assert_eq!(decode(&line), Some(behavior));
+        }
+        assert_eq!(decode("Part 1: 42"), None);
+    }
+}
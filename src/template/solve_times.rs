@@ -0,0 +1,306 @@
+/// Module that tracks when work on a day began and when each part was accepted.
+/// Timestamps are stored as seconds since the unix epoch.
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    path::PathBuf,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::server_time::unlock_timestamp;
+use crate::template::{config, Day};
+
+fn file_path() -> PathBuf {
+    let config = config::get();
+    config.path(&config.solve_times_file)
+}
+
+/// Current time in seconds since the unix epoch.
+#[allow(clippy::cast_possible_wrap)]
+pub fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+/// Represents the solve progress of a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolveTime {
+    pub day: Day,
+    pub started: Option<i64>,
+    pub part_1: Option<i64>,
+    pub part_2: Option<i64>,
+}
+
+impl SolveTime {
+    fn accepted(&self, part: u8) -> Option<i64> {
+        match part {
+            1 => self.part_1,
+            2 => self.part_2,
+            _ => None,
+        }
+    }
+
+    /// Seconds from starting the day until `part` was accepted.
+    pub fn time_to_part(&self, part: u8) -> Option<i64> {
+        Some(self.accepted(part)? - self.started?)
+    }
+
+    /// Seconds from the puzzle unlock until the last accepted part.
+    pub fn since_unlock(&self, year: u16) -> Option<i64> {
+        let finished = self.part_2.or(self.part_1)?;
+        Some(finished - unlock_timestamp(i64::from(year), self.day))
+    }
+}
+
+/// Represents the solve progress for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct SolveTimes {
+    pub data: Vec<SolveTime>,
+}
+
+impl SolveTimes {
+    /// Dehydrate solve times to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(file_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate solve times from a JSON file. If not present, returns empty solve times.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(file_path())
+            .map_err(|x| x.to_string())
+            .and_then(SolveTimes::try_from)
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, day: Day) -> Option<&SolveTime> {
+        self.data.iter().find(|t| t.day == day)
+    }
+
+    fn entry(&mut self, day: Day) -> &mut SolveTime {
+        let index = match self.data.iter().position(|t| t.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(SolveTime {
+                    day,
+                    started: None,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.sort_unstable_by_key(|t| t.day);
+                self.data.iter().position(|t| t.day == day).unwrap()
+            }
+        };
+        &mut self.data[index]
+    }
+
+    /// Records when work on a day began. Keeps an earlier start, returns whether it was recorded.
+    pub fn start(&mut self, day: Day, timestamp: i64) -> bool {
+        let entry = self.entry(day);
+        if entry.started.is_some() {
+            return false;
+        }
+        entry.started = Some(timestamp);
+        true
+    }
+
+    /// Records when a part was accepted. Keeps an earlier acceptance, returns whether it was recorded.
+    pub fn accept(&mut self, day: Day, part: u8, timestamp: i64) -> bool {
+        let entry = self.entry(day);
+        let accepted = match part {
+            1 => &mut entry.part_1,
+            2 => &mut entry.part_2,
+            _ => return false,
+        };
+        if accepted.is_some() {
+            return false;
+        }
+        *accepted = Some(timestamp);
+        true
+    }
+}
+
+/// Records that work on a day began, unless it was started before.
+pub fn record_start(day: Day) {
+    let mut times = SolveTimes::read_from_file();
+    if times.start(day, now()) {
+        if let Err(e) = times.store_file() {
+            eprintln!("Failed to store solve times: {e}");
+        }
+    }
+}
+
+/// Records that a part was accepted, unless it was accepted before.
+pub fn record_accepted(day: Day, part: u8) {
+    let mut times = SolveTimes::read_from_file();
+    if times.accept(day, part, now()) {
+        if let Err(e) = times.store_file() {
+            eprintln!("Failed to store solve times: {e}");
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<SolveTimes> for JsonValue {
+    fn from(value: SolveTimes) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for SolveTimes {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(SolveTimes {
+            data: json_data
+                .iter()
+                .map(SolveTime::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&SolveTime> for JsonValue {
+    fn from(value: &SolveTime) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let timestamp =
+            |ts: Option<i64>| ts.map_or(JsonValue::Null, |ts| JsonValue::Number(ts as f64));
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("started".into(), timestamp(value.started));
+        map.insert("part_1".into(), timestamp(value.part_1));
+        map.insert("part_2".into(), timestamp(value.part_2));
+
+        JsonValue::Object(map)
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+impl TryFrom<&JsonValue> for SolveTime {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected solve time to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected solve_time.day to be a Day struct.")?;
+
+        let timestamp = |key: &str| -> Result<Option<i64>, String> {
+            match json.get(key) {
+                None | Some(JsonValue::Null) => Ok(None),
+                Some(JsonValue::Number(ts)) => Ok(Some(*ts as i64)),
+                Some(_) => Err(format!("Expected solve_time.{key} to be null or a number.")),
+            }
+        };
+
+        Ok(SolveTime {
+            day,
+            started: timestamp("started")?,
+            part_1: timestamp("part_1")?,
+            part_2: timestamp("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{SolveTime, SolveTimes};
+    use crate::day;
+
+    #[test]
+    fn keeps_first_timestamps() {
+        let mut times = SolveTimes::default();
+
+        assert!(times.start(day!(2), 100));
+        assert!(!times.start(day!(2), 200));
+        assert!(times.accept(day!(2), 1, 300));
+        assert!(!times.accept(day!(2), 1, 400));
+        assert!(times.accept(day!(1), 2, 500));
+
+        assert_eq!(times.data[0].day, day!(1));
+        assert_eq!(
+            times.get(day!(2)),
+            Some(&SolveTime {
+                day: day!(2),
+                started: Some(100),
+                part_1: Some(300),
+                part_2: None,
+            })
+        );
+    }
+
+    #[test]
+    fn computes_durations() {
+        let time = SolveTime {
+            day: day!(1),
+            started: Some(1733029300),
+            part_1: Some(1733029500),
+            part_2: Some(1733030100),
+        };
+
+        assert_eq!(time.time_to_part(1), Some(200));
+        assert_eq!(time.time_to_part(2), Some(800));
+        // day 1 of 2024 unlocked at 1733029200.
+        assert_eq!(time.since_unlock(2024), Some(900));
+
+        let unstarted = SolveTime {
+            started: None,
+            ..time
+        };
+        assert_eq!(unstarted.time_to_part(1), None);
+    }
+
+    #[test]
+    fn serializes_round_trip() {
+        let mut times = SolveTimes::default();
+        times.start(day!(3), 1733200000);
+        times.accept(day!(3), 1, 1733200100);
+
+        let json = tinyjson::JsonValue::from(times.clone())
+            .stringify()
+            .unwrap();
+        let parsed = SolveTimes::try_from(json).unwrap();
+        assert_eq!(parsed.data, times.data);
+    }
+
+    #[test]
+    fn rejects_invalid_timestamps() {
+        let json = r#"{ "data": [{ "day": "01", "started": "yesterday" }] }"#.to_string();
+        assert!(SolveTimes::try_from(json).is_err());
+    }
+}
@@ -0,0 +1,325 @@
+/// Module that collects the progress of a day from the files on disk, its tests, stored answers and benchmarks.
+use std::{collections::HashMap, fs, path::Path};
+
+use crate::template::answers::Answers;
+use crate::template::config;
+use crate::template::encryption::encrypted_path;
+use crate::template::run_multi::child_commands;
+use crate::template::timings::Timings;
+use crate::template::Day;
+
+/// Progress of a single part of a day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartStatus {
+    /// `None` if no test exists for this part.
+    pub tests: Option<bool>,
+    pub answer: bool,
+    pub benched: bool,
+    /// Set if the answer or benchmark was recorded for a different input.
+    pub stale: bool,
+}
+
+impl PartStatus {
+    /// A part counts as solved once it was either accepted or produced a benchmarked result.
+    pub fn is_solved(&self) -> bool {
+        self.answer || self.benched
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.answer && self.benched
+    }
+
+    /// Symbol used to display this part in calendar views.
+    pub fn star(&self) -> char {
+        if self.is_complete() {
+            '★'
+        } else if self.is_solved() {
+            '☆'
+        } else {
+            '·'
+        }
+    }
+}
+
+/// Progress of a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayStatus {
+    pub day: Day,
+    pub scaffolded: bool,
+    pub input: bool,
+    pub example: bool,
+    pub parts: [PartStatus; 2],
+}
+
+impl DayStatus {
+    /// Collects the status of a day. Test results are left empty, see [`collect_test_results`].
+    pub fn collect(day: Day, timings: &Timings, answers: &Answers) -> Self {
+        let config = config::get();
+        let scaffolded = config.bin_path(day).exists();
+
+        let timing = timings.data.iter().find(|t| t.day == day);
+
+        let part = |part: u8| PartStatus {
+            tests: None,
+            answer: answers.get(day, part).is_some(),
+            benched: timing.and_then(|t| t.part_nanos(part)).is_some(),
+            stale: answers.is_stale(day, part) || timing.is_some_and(|t| t.stale),
+        };
+
+        DayStatus {
+            day,
+            scaffolded,
+            input: has_content(&config.input_path(day))
+                || has_content(&encrypted_path(&config.input_path(day))),
+            example: has_content(&config.example_path(day)),
+            parts: [part(1), part(2)],
+        }
+    }
+
+    /// Lists parts that are solved but are missing a benchmark or an accepted answer.
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = vec![];
+
+        for (i, part) in self.parts.iter().enumerate() {
+            if !part.is_solved() {
+                continue;
+            }
+            if part.stale {
+                problems.push(format!(
+                    "Day {} part {} was recorded for a different input.",
+                    self.day,
+                    i + 1
+                ));
+            }
+            if !part.benched {
+                problems.push(format!("Day {} part {} is not benched.", self.day, i + 1));
+            }
+            if !part.answer {
+                problems.push(format!(
+                    "Day {} part {} has no accepted answer.",
+                    self.day,
+                    i + 1
+                ));
+            }
+        }
+
+        problems
+    }
+}
+
+fn has_content(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+/// Runs the tests of several days in a single `cargo test` invocation and maps their results to parts.
+/// Days that are missing from the output, e.g. because the bin of another day failed to compile, are tested on their own.
+pub fn collect_test_results(days: &[Day]) -> HashMap<Day, [Option<bool>; 2]> {
+    let config = config::get();
+    let days: Vec<Day> = days
+        .iter()
+        .copied()
+        .filter(|day| config.bin_path(*day).exists())
+        .collect();
+
+    let sources: Vec<(Day, String)> = days
+        .iter()
+        .map(|day| {
+            let source = config.bin_link(*day);
+            (*day, source.trim_start_matches("./").to_string())
+        })
+        .collect();
+
+    let output = child_commands::run_tests_of_days(&days).unwrap_or_default();
+    let mut outputs = split_test_output(&sources, &output);
+
+    days.into_iter()
+        .map(|day| {
+            let output = outputs
+                .remove(&day)
+                .or_else(|| child_commands::run_tests(day).ok())
+                .unwrap_or_default();
+            (day, parse_test_results(&output))
+        })
+        .collect()
+}
+
+/// Splits the output of `cargo test` over several bins by the bin that wrote it. The output of a bin starts
+/// with a line like `Running unittests src/bin/01.rs (target/debug/deps/01-...)`.
+pub fn split_test_output(
+    sources: &[(Day, String)],
+    output: &[String],
+) -> HashMap<Day, Vec<String>> {
+    let mut outputs: HashMap<Day, Vec<String>> = HashMap::new();
+    let mut current = None;
+
+    for line in output {
+        if let Some(running) = line.trim_start().strip_prefix("Running ") {
+            current = sources
+                .iter()
+                .find(|(_, source)| running.split_whitespace().any(|word| word == source))
+                .map(|(day, _)| *day);
+            if let Some(day) = current {
+                outputs.entry(day).or_default();
+            }
+            continue;
+        }
+
+        if let Some(day) = current {
+            outputs.entry(day).or_default().push(line.clone());
+        }
+    }
+
+    outputs
+}
+
+/// Maps the output of `cargo test` to a pass / fail state for each part.
+/// Tests are attributed to a part by the `part_one` / `part_two` naming convention of the template.
+pub fn parse_test_results(output: &[String]) -> [Option<bool>; 2] {
+    let mut results = [None, None];
+
+    for line in output {
+        let Some(test) = line.strip_prefix("test ") else {
+            continue;
+        };
+
+        let index = if test.contains("part_one") {
+            0
+        } else if test.contains("part_two") {
+            1
+        } else {
+            continue;
+        };
+
+        let passed = test.ends_with("... ok");
+        results[index] = Some(results[index].unwrap_or(true) && passed);
+    }
+
+    results
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_test_results, split_test_output, DayStatus, PartStatus};
+    use crate::day;
+
+    #[test]
+    fn parses_test_results() {
+        let output: Vec<String> = [
+            "running 3 tests",
+            "test tests::test_part_one ... ok",
+            "test tests::test_part_two ... FAILED",
+            "test tests::test_part_two_second_example ... ok",
+            "",
+            "test result: FAILED. 2 passed; 1 failed",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        assert_eq!(parse_test_results(&output), [Some(true), Some(false)]);
+    }
+
+    #[test]
+    fn splits_test_output_by_bin() {
+        let sources = [
+            (day!(1), "src/bin/01.rs".to_string()),
+            (day!(2), "src/bin/02.rs".to_string()),
+        ];
+        let output: Vec<String> = [
+            "   Compiling advent_of_code v0.11.0",
+            "     Running unittests src/bin/01.rs (target/debug/deps/01-6dfa1558505ac623)",
+            "running 1 test",
+            "test tests::test_part_one ... ok",
+            "     Running unittests src/bin/02.rs (target/debug/deps/02-8fd860365698489a)",
+            "running 2 tests",
+            "test tests::test_part_one ... ok",
+            "test tests::test_part_two ... FAILED",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        let outputs = split_test_output(&sources, &output);
+        assert_eq!(outputs[&day!(1)].len(), 2);
+        assert_eq!(parse_test_results(&outputs[&day!(1)]), [Some(true), None]);
+        assert_eq!(
+            parse_test_results(&outputs[&day!(2)]),
+            [Some(true), Some(false)]
+        );
+        assert!(split_test_output(&sources, &output[..1]).is_empty());
+    }
+
+    #[test]
+    fn parses_missing_tests() {
+        let output = vec!["test tests::test_part_one ... ok".to_string()];
+        assert_eq!(parse_test_results(&output), [Some(true), None]);
+        assert_eq!(parse_test_results(&[]), [None, None]);
+    }
+
+    #[test]
+    fn reports_solved_parts_with_missing_data() {
+        let status = DayStatus {
+            day: day!(3),
+            scaffolded: true,
+            input: true,
+            example: true,
+            parts: [
+                PartStatus {
+                    tests: Some(true),
+                    answer: true,
+                    benched: false,
+                    stale: false,
+                },
+                PartStatus {
+                    tests: Some(true),
+                    answer: false,
+                    benched: false,
+                    stale: false,
+                },
+            ],
+        };
+
+        assert_eq!(status.problems(), vec!["Day 03 part 1 is not benched."]);
+    }
+
+    #[test]
+    fn reports_nothing_for_complete_days() {
+        let part = PartStatus {
+            tests: Some(true),
+            answer: true,
+            benched: true,
+            stale: false,
+        };
+        let status = DayStatus {
+            day: day!(1),
+            scaffolded: true,
+            input: true,
+            example: true,
+            parts: [part.clone(), part],
+        };
+
+        assert!(status.problems().is_empty());
+    }
+
+    #[test]
+    fn reports_stale_parts() {
+        let part = PartStatus {
+            tests: None,
+            answer: true,
+            benched: true,
+            stale: true,
+        };
+        let status = DayStatus {
+            day: day!(5),
+            scaffolded: true,
+            input: true,
+            example: true,
+            parts: [part, PartStatus::default()],
+        };
+
+        assert_eq!(
+            status.problems(),
+            vec!["Day 05 part 1 was recorded for a different input."]
+        );
+    }
+}
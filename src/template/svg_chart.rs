@@ -0,0 +1,262 @@
+/// Module that renders benchmark timings as a standalone SVG bar chart.
+/// Every day is drawn as one horizontal bar, split into its part 1 and part 2 segments.
+use std::fmt::Write;
+
+use crate::template::timings::Timings;
+
+const ROW_HEIGHT: f64 = 22.0;
+const BAR_HEIGHT: f64 = 16.0;
+const LABEL_WIDTH: f64 = 64.0;
+const BAR_WIDTH: f64 = 480.0;
+const VALUE_WIDTH: f64 = 96.0;
+const LEGEND_HEIGHT: f64 = 28.0;
+const AXIS_HEIGHT: f64 = 18.0;
+
+const PART_1_COLOR: &str = "#4e79a7";
+const PART_2_COLOR: &str = "#f28e2b";
+
+/// How bar lengths relate to durations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scale {
+    /// Bars are proportional to duration, parts are stacked into one bar per day.
+    Linear,
+    /// Bars are proportional to the order of magnitude of a duration, parts are drawn as separate bars.
+    Log,
+}
+
+/// Renders a bar chart of part 1 / part 2 durations for every benched day.
+pub fn bar_chart(timings: &Timings, scale: Scale) -> String {
+    let rows: Vec<_> = timings
+        .data
+        .iter()
+        .map(|timing| {
+            (
+                timing.day,
+                timing.part_nanos(1).unwrap_or(0.0),
+                timing.part_nanos(2).unwrap_or(0.0),
+            )
+        })
+        .collect();
+
+    let axis = match scale {
+        Scale::Linear => Axis::Linear {
+            max_nanos: rows
+                .iter()
+                .map(|(_, part_1, part_2)| part_1 + part_2)
+                .fold(0.0, f64::max),
+        },
+        Scale::Log => Axis::log(
+            rows.iter()
+                .flat_map(|(_, part_1, part_2)| [*part_1, *part_2]),
+        ),
+    };
+
+    let width = LABEL_WIDTH + BAR_WIDTH + VALUE_WIDTH;
+    #[allow(clippy::cast_precision_loss)]
+    let chart_bottom = LEGEND_HEIGHT + ROW_HEIGHT * rows.len() as f64;
+    let height = match axis {
+        Axis::Linear { .. } => chart_bottom,
+        Axis::Log { .. } => chart_bottom + AXIS_HEIGHT,
+    };
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif" font-size="12">"#
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect x="{LABEL_WIDTH}" y="6" width="12" height="12" fill="{PART_1_COLOR}"/><text x="{}" y="16">Part 1</text>"#,
+        LABEL_WIDTH + 16.0
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect x="{}" y="6" width="12" height="12" fill="{PART_2_COLOR}"/><text x="{}" y="16">Part 2</text>"#,
+        LABEL_WIDTH + 72.0,
+        LABEL_WIDTH + 88.0
+    );
+
+    if let Axis::Log { min_exp, max_exp } = axis {
+        for exp in min_exp..=max_exp {
+            let x = LABEL_WIDTH + axis.scale(10_f64.powi(exp));
+            let _ = writeln!(
+                svg,
+                r##"<line x1="{x:.2}" y1="{LEGEND_HEIGHT}" x2="{x:.2}" y2="{chart_bottom}" stroke="#d0d7de"/><text x="{:.2}" y="{}" fill="#57606a">{}</text>"##,
+                x + 2.0,
+                height - 4.0,
+                format_decade(exp)
+            );
+        }
+    }
+
+    for (i, (day, part_1, part_2)) in rows.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let y = LEGEND_HEIGHT + ROW_HEIGHT * i as f64;
+        let part_1_width = axis.scale(*part_1);
+        let part_2_width = axis.scale(*part_2);
+
+        // stacked bars share one row, log-scaled bars are split into a row per part.
+        let (part_2_x, part_2_y, bar_height, value_x) = match axis {
+            Axis::Linear { .. } => (
+                LABEL_WIDTH + part_1_width,
+                y,
+                BAR_HEIGHT,
+                LABEL_WIDTH + part_1_width + part_2_width + 6.0,
+            ),
+            Axis::Log { .. } => (
+                LABEL_WIDTH,
+                y + BAR_HEIGHT / 2.0,
+                BAR_HEIGHT / 2.0,
+                LABEL_WIDTH + BAR_WIDTH + 6.0,
+            ),
+        };
+
+        let _ = writeln!(
+            svg,
+            r#"<text x="0" y="{}">Day {day}</text>"#,
+            y + BAR_HEIGHT - 4.0
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{LABEL_WIDTH}" y="{y}" width="{part_1_width:.2}" height="{bar_height}" fill="{PART_1_COLOR}"><title>Day {day} part 1: {}</title></rect>"#,
+            format_millis(*part_1)
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{part_2_x:.2}" y="{part_2_y}" width="{part_2_width:.2}" height="{bar_height}" fill="{PART_2_COLOR}"><title>Day {day} part 2: {}</title></rect>"#,
+            format_millis(*part_2)
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="{value_x:.2}" y="{}">{}</text>"#,
+            y + BAR_HEIGHT - 4.0,
+            format_millis(part_1 + part_2)
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[derive(Clone, Copy)]
+enum Axis {
+    Linear { max_nanos: f64 },
+    Log { min_exp: i32, max_exp: i32 },
+}
+
+impl Axis {
+    /// Spans whole decades around the given durations, ignoring parts that did not run.
+    /// The lower bound lies strictly below the fastest part, so every bar stays visible.
+    fn log(nanos: impl Iterator<Item = f64>) -> Self {
+        let (min, max) = nanos
+            .filter(|n| *n > 0.0)
+            .fold((f64::INFINITY, 0_f64), |(min, max), n| {
+                (min.min(n), max.max(n))
+            });
+
+        if max <= 0.0 {
+            return Axis::Log {
+                min_exp: 0,
+                max_exp: 1,
+            };
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let min_exp = min.log10().ceil() as i32 - 1;
+        #[allow(clippy::cast_possible_truncation)]
+        let max_exp = (max.log10().ceil() as i32).max(min_exp + 1);
+
+        Axis::Log { min_exp, max_exp }
+    }
+
+    /// Converts a duration into a bar length.
+    fn scale(self, nanos: f64) -> f64 {
+        match self {
+            Axis::Linear { max_nanos } => {
+                if max_nanos <= 0.0 {
+                    0.0
+                } else {
+                    nanos / max_nanos * BAR_WIDTH
+                }
+            }
+            Axis::Log { min_exp, max_exp } => {
+                if nanos <= 0.0 {
+                    0.0
+                } else {
+                    let min = f64::from(min_exp);
+                    let max = f64::from(max_exp);
+                    (nanos.log10() - min) / (max - min) * BAR_WIDTH
+                }
+            }
+        }
+    }
+}
+
+fn format_millis(nanos: f64) -> String {
+    format!("{:.2}ms", nanos / 1_000_000_f64)
+}
+
+fn format_decade(exp: i32) -> String {
+    match exp {
+        ..=2 => format!("{}ns", 10_u64.pow(exp.max(0).unsigned_abs())),
+        3..=5 => format!("{}µs", 10_u64.pow((exp - 3).unsigned_abs())),
+        6..=8 => format!("{}ms", 10_u64.pow((exp - 6).unsigned_abs())),
+        _ => format!("{}s", 10_u64.pow((exp - 9).unsigned_abs())),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{bar_chart, format_decade, Scale};
+    use crate::template::timings::{get_mock_timings, Timings};
+
+    #[test]
+    fn renders_a_row_per_day() {
+        let svg = bar_chart(&get_mock_timings(), Scale::Linear);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<title>").count(), 6);
+        assert!(svg.contains("Day 01 part 2: 20.00ms"));
+        assert!(svg.contains("Day 04 part 2: 0.00ms"));
+    }
+
+    #[test]
+    fn scales_bars_to_the_slowest_day() {
+        let svg = bar_chart(&get_mock_timings(), Scale::Linear);
+        // day 2 is the slowest day with 70ms in total, so its part 2 bar spans 4/7 of the chart.
+        assert!(svg.contains(r##"x="269.71" y="50" width="274.29" height="16" fill="#f28e2b""##));
+        assert!(svg.contains(r##"x="64" y="28" width="68.57" height="16" fill="#4e79a7""##));
+    }
+
+    #[test]
+    fn handles_empty_timings() {
+        let svg = bar_chart(&Timings::default(), Scale::Linear);
+        assert!(!svg.contains("<title>"));
+    }
+
+    #[test]
+    fn splits_parts_on_log_scale() {
+        let svg = bar_chart(&get_mock_timings(), Scale::Log);
+        // durations range from 10ms to 40ms, so the axis spans 1ms to 100ms.
+        assert!(svg.contains(">1ms</text>"));
+        assert!(svg.contains(">100ms</text>"));
+        assert!(svg.contains(r##"x="64" y="28" width="240.00" height="8" fill="#4e79a7""##));
+        assert!(svg.contains(r##"x="64.00" y="36" width="312.25" height="8" fill="#f28e2b""##));
+        assert!(svg.contains(r##"x="64.00" y="80" width="0.00" height="8" fill="#f28e2b""##));
+    }
+
+    #[test]
+    fn handles_empty_timings_on_log_scale() {
+        let svg = bar_chart(&Timings::default(), Scale::Log);
+        assert!(!svg.contains("<title>"));
+    }
+
+    #[test]
+    fn formats_decades() {
+        assert_eq!(format_decade(0), "1ns");
+        assert_eq!(format_decade(2), "100ns");
+        assert_eq!(format_decade(4), "10µs");
+        assert_eq!(format_decade(6), "1ms");
+        assert_eq!(format_decade(10), "10s");
+    }
+}
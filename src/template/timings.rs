@@ -1,9 +1,13 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::run_multi::child_commands;
+use crate::template::{config, Day};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+fn file_path() -> PathBuf {
+    let config = config::get();
+    config.path(&config.timings_file)
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -12,6 +16,8 @@ pub struct Timing {
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    /// Set if the input changed since the benchmark was stored.
+    pub stale: bool,
 }
 
 /// Represents benchmark times for a set of days.
@@ -25,13 +31,13 @@ impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(file_path())?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+        fs::read_to_string(file_path())
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,7 +57,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,6 +66,17 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
+    /// Marks the benchmark of a day as stale, returns whether a benchmark was found.
+    pub fn mark_stale(&mut self, day: Day) -> bool {
+        match self.data.iter_mut().find(|t| t.day == day && !t.stale) {
+            Some(timing) => {
+                timing.stale = true;
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn is_day_complete(&self, day: Day) -> bool {
         self.data
             .iter()
@@ -67,6 +84,18 @@ impl Timings {
     }
 }
 
+impl Timing {
+    /// Duration of a single part in nanoseconds, if that part was benched.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        let timing = match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }?;
+        child_commands::parse_nanos(timing)
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
@@ -113,6 +142,7 @@ impl From<&Timing> for JsonValue {
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+        map.insert("stale".into(), JsonValue::Boolean(value.stale));
 
         let part_1 = value.part_1.clone().map(JsonValue::String);
         let part_2 = value.part_2.clone().map(JsonValue::String);
@@ -166,47 +196,60 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        let stale = match json.get("stale") {
+            None => false,
+            Some(v) => *v
+                .get::<bool>()
+                .ok_or("Expected timing.stale to be a boolean.")?,
+        };
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
+            stale,
         })
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
+/// Timings of two complete days and one partial day, for the tests of modules that work with timings.
 #[cfg(feature = "test_lib")]
-mod tests {
+pub fn get_mock_timings() -> Timings {
     use crate::day;
 
-    use super::{Timing, Timings};
-
-    fn get_mock_timings() -> Timings {
-        Timings {
-            data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
-                    total_nanos: 4e+10,
-                },
-            ],
-        }
+    Timings {
+        data: vec![
+            Timing {
+                day: day!(1),
+                part_1: Some("10ms".into()),
+                part_2: Some("20ms".into()),
+                total_nanos: 3e+7,
+                stale: false,
+            },
+            Timing {
+                day: day!(2),
+                part_1: Some("30ms".into()),
+                part_2: Some("40ms".into()),
+                total_nanos: 7e+7,
+                stale: false,
+            },
+            Timing {
+                day: day!(4),
+                part_1: Some("40ms".into()),
+                part_2: None,
+                total_nanos: 4e+7,
+                stale: false,
+            },
+        ],
     }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::get_mock_timings;
 
     mod deserialization {
         use crate::{day, template::timings::Timings};
@@ -221,6 +264,14 @@ mod tests {
             assert_eq!(timing.part_1, Some("1ms".to_string()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.stale, false);
+        }
+
+        #[test]
+        fn handles_stale_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": null, "part_2": null, "total_nanos": 0, "stale": true }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].stale, true);
         }
 
         #[test]
@@ -282,10 +333,11 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    stale: false,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -296,10 +348,11 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    stale: false,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -310,10 +363,11 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    stale: false,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -334,6 +388,7 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    stale: false,
                 }],
             };
             let merged = timings.merge(&other);
@@ -354,6 +409,7 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    stale: false,
                 }],
             };
             let merged = timings.merge(&other);
@@ -381,4 +437,20 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
     }
+
+    mod mark_stale {
+        use crate::day;
+
+        use super::get_mock_timings;
+
+        #[test]
+        fn marks_stored_days() {
+            let mut timings = get_mock_timings();
+            assert_eq!(timings.mark_stale(day!(2)), true);
+            assert_eq!(timings.mark_stale(day!(2)), false);
+            assert_eq!(timings.mark_stale(day!(3)), false);
+            assert_eq!(timings.data[1].stale, true);
+            assert_eq!(timings.data[0].stale, false);
+        }
+    }
 }
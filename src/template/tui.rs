@@ -0,0 +1,569 @@
+/// Module that implements an interactive, full-screen dashboard for browsing days, answers and timings.
+/// Drawing uses plain ANSI escape sequences, raw keyboard input is enabled through `stty`.
+use std::{
+    io::{self, Read, Write},
+    process::{Command, Stdio},
+    time::{Duration, Instant},
+};
+
+use crate::template::answers::Answers;
+use crate::template::config;
+use crate::template::encryption;
+use crate::template::markdown;
+use crate::template::run_multi::child_commands::{self, OutputLine};
+use crate::template::status::{parse_test_results, DayStatus};
+use crate::template::timings::Timings;
+use crate::template::{all_days, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const ANSI_REVERSE: &str = "\x1b[7m";
+const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+const ANSI_ENTER_SCREEN: &str = "\x1b[?1049h\x1b[?25l";
+const ANSI_LEAVE_SCREEN: &str = "\x1b[?25h\x1b[?1049l";
+
+const LIST_WIDTH: usize = 10;
+const SEPARATOR: &str = " │ ";
+const HELP: &str = "↑/↓ day  r run  b bench  t test  p puzzle  o output  PgUp/PgDn scroll  q quit";
+/// Minimum time between redraws while a command is streaming output.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Runs the dashboard until the user quits.
+pub fn run() -> io::Result<()> {
+    config::load().map_err(io::Error::other)?;
+    let _terminal = RawTerminal::enter()?;
+    let mut dashboard = Dashboard::new();
+    let mut keys = Keys::new(io::stdin().lock());
+
+    loop {
+        dashboard.draw()?;
+
+        match keys.next()? {
+            Some(Key::Char('q')) => break,
+            Some(Key::Up | Key::Char('k')) => {
+                dashboard.select(dashboard.selected.saturating_sub(1))
+            }
+            Some(Key::Down | Key::Char('j')) => dashboard.select(dashboard.selected + 1),
+            Some(Key::PageUp) => dashboard.scroll_by(-1),
+            Some(Key::PageDown) => dashboard.scroll_by(1),
+            Some(Key::Char('p')) => dashboard.show(Pane::Puzzle),
+            Some(Key::Char('o')) => dashboard.show(Pane::Output),
+            Some(Key::Char('r')) => dashboard.run_solution(false),
+            Some(Key::Char('b')) => dashboard.run_solution(true),
+            Some(Key::Char('t')) => dashboard.run_tests(),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Switches the terminal to raw mode on the alternate screen and restores it when dropped.
+struct RawTerminal {
+    saved_mode: String,
+}
+
+impl RawTerminal {
+    fn enter() -> io::Result<Self> {
+        let saved_mode = stty(&["-g"])?.trim().to_string();
+        // reads block for the first byte and then return what arrives within 100ms, see `Keys`.
+        stty(&["raw", "-echo", "min", "1", "time", "1"])?;
+        print!("{ANSI_ENTER_SCREEN}");
+        io::stdout().flush()?;
+        Ok(Self { saved_mode })
+    }
+
+    /// Returns the terminal size as `(rows, columns)`.
+    fn size() -> (usize, usize) {
+        stty(&["size"])
+            .ok()
+            .and_then(|size| {
+                let (rows, cols) = size.trim().split_once(' ')?;
+                Some((rows.parse().ok()?, cols.parse().ok()?))
+            })
+            .unwrap_or((24, 80))
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        print!("{ANSI_LEAVE_SCREEN}");
+        let _ = io::stdout().flush();
+        let _ = stty(&[&self.saved_mode]);
+    }
+}
+
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(
+            "stty failed, the dashboard requires an interactive unix terminal.",
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, PartialEq, Eq)]
+enum Key {
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Char(char),
+}
+
+/// Reads key presses from the terminal. In raw mode with `min 1 time 1`, a read waits for the first byte
+/// and then returns everything that arrives within 100ms, so the bytes of an escape sequence arrive
+/// together and a lone Esc does not block until the next key press.
+struct Keys<R> {
+    input: R,
+    buffer: Vec<u8>,
+}
+
+impl<R: Read> Keys<R> {
+    fn new(input: R) -> Self {
+        Keys {
+            input,
+            buffer: vec![],
+        }
+    }
+
+    /// Reads a single key press. Keys that are not handled by the dashboard map to `None`.
+    fn next(&mut self) -> io::Result<Option<Key>> {
+        if self.buffer.is_empty() {
+            let mut chunk = [0_u8; 32];
+            let len = self.input.read(&mut chunk)?;
+            if len == 0 {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            self.buffer.extend_from_slice(&chunk[..len]);
+        }
+
+        let (key, len) = parse_key(&self.buffer);
+        self.buffer.drain(..len);
+        Ok(key)
+    }
+}
+
+/// Parses the key at the start of `bytes`, returning it and the number of bytes it spans.
+fn parse_key(bytes: &[u8]) -> (Option<Key>, usize) {
+    match bytes {
+        // raw mode does not translate ctrl-c into a signal.
+        [0x03, ..] => (Some(Key::Char('q')), 1),
+        [0x1b, b'[', b'A', ..] => (Some(Key::Up), 3),
+        [0x1b, b'[', b'B', ..] => (Some(Key::Down), 3),
+        [0x1b, b'[', b'5', b'~', ..] => (Some(Key::PageUp), 4),
+        [0x1b, b'[', b'6', b'~', ..] => (Some(Key::PageDown), 4),
+        // skip other CSI sequences up to their final byte.
+        [0x1b, b'[', rest @ ..] => {
+            let len = rest
+                .iter()
+                .position(|b| (0x40..=0x7e).contains(b))
+                .map_or(bytes.len(), |i| i + 3);
+            (None, len)
+        }
+        [b, ..] if *b != 0x1b && b.is_ascii() => (Some(Key::Char(char::from(*b))), 1),
+        _ => (None, 1),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Pane {
+    Puzzle,
+    Output,
+}
+
+struct Dashboard {
+    timings: Timings,
+    answers: Answers,
+    statuses: Vec<DayStatus>,
+    selected: usize,
+    pane: Pane,
+    /// Rendered puzzle description of the selected day, loaded when the selection changes.
+    puzzle: Vec<String>,
+    output: Vec<String>,
+    scroll: usize,
+    message: String,
+    size: (usize, usize),
+    last_draw: Instant,
+}
+
+impl Dashboard {
+    fn new() -> Self {
+        let timings = Timings::read_from_file();
+        let answers = Answers::read_from_file();
+        let statuses = all_days()
+            .map(|day| DayStatus::collect(day, &timings, &answers))
+            .collect();
+
+        let mut dashboard = Dashboard {
+            timings,
+            answers,
+            statuses,
+            selected: 0,
+            pane: Pane::Puzzle,
+            puzzle: vec![],
+            output: vec![],
+            scroll: 0,
+            message: String::new(),
+            size: RawTerminal::size(),
+            last_draw: Instant::now(),
+        };
+        dashboard.load_puzzle();
+        dashboard
+    }
+
+    fn day(&self) -> Day {
+        self.statuses[self.selected].day
+    }
+
+    fn select(&mut self, index: usize) {
+        if index < self.statuses.len() && index != self.selected {
+            self.selected = index;
+            self.pane = Pane::Puzzle;
+            self.scroll = 0;
+            self.load_puzzle();
+        }
+    }
+
+    fn load_puzzle(&mut self) {
+        let day = self.day();
+        self.puzzle = match encryption::read_to_string(&config::get().puzzle_path(day)) {
+            Ok(puzzle) => markdown::render(&puzzle)
+                .lines()
+                .map(String::from)
+                .collect(),
+            Err(_) => vec![format!(
+                "No puzzle description found. Run `cargo download {day}` to fetch it."
+            )],
+        };
+    }
+
+    fn show(&mut self, pane: Pane) {
+        self.pane = pane;
+        self.scroll = 0;
+    }
+
+    fn scroll_by(&mut self, pages: isize) {
+        let page = self.content_height().max(1);
+        self.scroll = self
+            .scroll
+            .saturating_add_signed(pages * page.cast_signed())
+            .min(self.content().len().saturating_sub(1));
+    }
+
+    /// Runs the selected day in release mode. If `bench` is set, the timings are stored.
+    fn run_solution(&mut self, bench: bool) {
+        let day = self.day();
+        let verb = if bench { "Benching" } else { "Running" };
+        self.start_command(format!("{verb} day {day}..."));
+
+        let result = child_commands::run_solution_streamed(day, bench, true, |line| {
+            self.push_output(line);
+        });
+
+        match result {
+            Ok(output) if output.is_empty() => self.message = format!("Day {day} is not solved."),
+            Ok(output) if bench => {
+                let timing = child_commands::parse_exec_time(&output, day);
+                self.timings = self.timings.merge(&Timings { data: vec![timing] });
+                self.message = match self.timings.store_file() {
+                    Ok(()) => format!("Stored updated benchmarks for day {day}."),
+                    Err(e) => format!("Failed to store benchmarks: {e}"),
+                };
+            }
+            Ok(_) => self.message = format!("Finished day {day}."),
+            Err(e) => self.message = format!("Failed to run day {day}: {e:?}"),
+        }
+
+        self.refresh_status();
+    }
+
+    fn run_tests(&mut self) {
+        let day = self.day();
+        self.start_command(format!("Testing day {day}..."));
+
+        let result = child_commands::run_tests_streamed(day, |line| {
+            self.push_output(line);
+        });
+
+        match result {
+            Ok(output) => {
+                let results = parse_test_results(&output);
+                self.message = match results {
+                    [None, None] => format!("Day {day} has no tests."),
+                    [Some(false), _] | [_, Some(false)] => format!("Tests for day {day} failed."),
+                    _ => format!("Tests for day {day} passed."),
+                };
+                for (part, result) in self.statuses[self.selected].parts.iter_mut().zip(results) {
+                    part.tests = result;
+                }
+            }
+            Err(e) => self.message = format!("Failed to test day {day}: {e:?}"),
+        }
+    }
+
+    fn start_command(&mut self, message: String) {
+        self.pane = Pane::Output;
+        self.output.clear();
+        self.scroll = 0;
+        self.message = message;
+        let _ = self.draw();
+    }
+
+    /// Appends a line of child output, following the tail of the output.
+    /// Redraws are throttled, the main loop draws the final state once the command finished.
+    fn push_output(&mut self, line: &OutputLine) {
+        let (OutputLine::Stdout(line) | OutputLine::Stderr(line)) = line;
+        self.output.push(clean_line(line));
+        self.scroll = self.output.len().saturating_sub(self.content_height());
+        if self.last_draw.elapsed() >= REDRAW_INTERVAL {
+            let _ = self.draw();
+        }
+    }
+
+    fn refresh_status(&mut self) {
+        let tests = self.statuses[self.selected]
+            .parts
+            .clone()
+            .map(|part| part.tests);
+        let mut status = DayStatus::collect(self.day(), &self.timings, &self.answers);
+        for (part, tests) in status.parts.iter_mut().zip(tests) {
+            part.tests = tests;
+        }
+        self.statuses[self.selected] = status;
+    }
+
+    /* ---------------------------------------------------------------------- */
+
+    fn content(&self) -> &[String] {
+        match self.pane {
+            Pane::Output => &self.output,
+            Pane::Puzzle => &self.puzzle,
+        }
+    }
+
+    /// Number of rows available to the puzzle / output pane.
+    fn content_height(&self) -> usize {
+        // title, blank line, 3 detail lines, blank line, pane title and the message line.
+        self.size.0.saturating_sub(8)
+    }
+
+    fn details(&self) -> Vec<(bool, String)> {
+        let day = self.day();
+        let status = &self.statuses[self.selected];
+        let timing = self.timings.data.iter().find(|t| t.day == day);
+
+        let mut lines = vec![(true, format!("Day {day}"))];
+
+        for (i, part) in status.parts.iter().enumerate() {
+            let part_number = u8::try_from(i + 1).unwrap();
+            let time = timing.and_then(|t| match part_number {
+                1 => t.part_1.clone(),
+                _ => t.part_2.clone(),
+            });
+            lines.push((
+                false,
+                format!(
+                    "Part {part_number}  answer: {:<16} time: {:<12} tests: {}",
+                    self.answers.get(day, part_number).unwrap_or("-"),
+                    time.as_deref().unwrap_or("-"),
+                    match part.tests {
+                        Some(true) => "✔",
+                        Some(false) => "✖",
+                        None => "-",
+                    }
+                ),
+            ));
+        }
+
+        lines.push((false, String::new()));
+        lines.push((
+            true,
+            match self.pane {
+                Pane::Puzzle => "Puzzle".into(),
+                Pane::Output => "Output".into(),
+            },
+        ));
+
+        lines.extend(
+            self.content()
+                .iter()
+                .skip(self.scroll)
+                .take(self.content_height())
+                .map(|line| (false, line.clone())),
+        );
+
+        lines
+    }
+
+    fn render(&self) -> String {
+        let (rows, cols) = self.size;
+        let detail_width = cols.saturating_sub(LIST_WIDTH + SEPARATOR.chars().count());
+        let details = self.details();
+
+        let mut lines = vec![
+            format!(
+                "{ANSI_BOLD}{}{ANSI_RESET}",
+                fit(&format!("Advent of Code  {HELP}"), cols)
+            ),
+            String::new(),
+        ];
+
+        for row in 0..rows.saturating_sub(3) {
+            let list_item = self.statuses.get(row).map_or_else(
+                || fit("", LIST_WIDTH),
+                |status| {
+                    let stars: String = status.parts.iter().map(|p| p.star()).collect();
+                    let item = fit(&format!(" {} {stars}", status.day), LIST_WIDTH);
+                    if row == self.selected {
+                        format!("{ANSI_REVERSE}{item}{ANSI_RESET}")
+                    } else if status.scaffolded {
+                        item
+                    } else {
+                        format!("{ANSI_ITALIC}{item}{ANSI_RESET}")
+                    }
+                },
+            );
+
+            let detail = details.get(row).map_or_else(String::new, |(bold, text)| {
+                let text = fit(text, detail_width);
+                if *bold {
+                    format!("{ANSI_BOLD}{text}{ANSI_RESET}")
+                } else {
+                    text
+                }
+            });
+
+            lines.push(format!("{list_item}{SEPARATOR}{detail}"));
+        }
+
+        lines.push(format!(
+            "{ANSI_ITALIC}{}{ANSI_RESET}",
+            fit(&self.message, cols)
+        ));
+
+        format!("{ANSI_CLEAR}{}", lines.join("\r\n"))
+    }
+
+    fn draw(&mut self) -> io::Result<()> {
+        self.size = RawTerminal::size();
+        let mut stdout = io::stdout();
+        stdout.write_all(self.render().as_bytes())?;
+        self.last_draw = Instant::now();
+        stdout.flush()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Truncates or pads a string to exactly `width` visible characters.
+/// Escape sequences are kept, so styles of truncated text are still reset.
+fn fit(s: &str, width: usize) -> String {
+    let mut fitted = String::with_capacity(s.len());
+    let mut visible = 0;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            fitted.push(c);
+            for c in chars.by_ref() {
+                fitted.push(c);
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else if visible < width {
+            fitted.push(c);
+            visible += 1;
+        }
+    }
+
+    fitted.extend(std::iter::repeat_n(' ', width - visible));
+    fitted
+}
+
+/// Prepares a line of child output for display: only keeps what remains visible after carriage returns,
+/// and removes escape sequences and tabs that would break the layout.
+fn clean_line(line: &str) -> String {
+    let visible = line
+        .rsplit('\r')
+        .find(|segment| !segment.trim().is_empty())
+        .unwrap_or_default();
+
+    let mut cleaned = String::with_capacity(visible.len());
+    let mut chars = visible.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => {
+                // skip a CSI sequence up to its final byte.
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+            '\t' => cleaned.push_str("    "),
+            c => cleaned.push(c),
+        }
+    }
+
+    cleaned
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::io::Read;
+
+    use super::{clean_line, fit, Key, Keys};
+
+    #[test]
+    fn fits_strings_to_width() {
+        assert_eq!(fit("abc", 5), "abc  ");
+        assert_eq!(fit("abcdef", 3), "abc");
+        assert_eq!(fit("★★", 3), "★★ ");
+        assert_eq!(fit("\x1b[1mabc\x1b[0m", 2), "\x1b[1mab\x1b[0m");
+    }
+
+    #[test]
+    fn cleans_child_output() {
+        assert_eq!(
+            clean_line("Part 1: 42\rPart 1: \x1b[1m42\x1b[0m (1.0ms)"),
+            "Part 1: 42 (1.0ms)"
+        );
+        assert_eq!(clean_line("Part 2: ✖\rPart 2: ✖   \r"), "Part 2: ✖   ");
+        assert_eq!(clean_line("\ta"), "    a");
+    }
+
+    #[test]
+    fn reads_keys() {
+        let mut keys = Keys::new(&b"q\x1b[A\x1b[B\x1b[5~\x1b[6~\x1b[1;2C\x03"[..]);
+        assert_eq!(keys.next().unwrap(), Some(Key::Char('q')));
+        assert_eq!(keys.next().unwrap(), Some(Key::Up));
+        assert_eq!(keys.next().unwrap(), Some(Key::Down));
+        assert_eq!(keys.next().unwrap(), Some(Key::PageUp));
+        assert_eq!(keys.next().unwrap(), Some(Key::PageDown));
+        assert_eq!(keys.next().unwrap(), None);
+        assert_eq!(keys.next().unwrap(), Some(Key::Char('q')));
+        assert!(keys.next().is_err());
+    }
+
+    #[test]
+    fn does_not_wait_after_escape() {
+        // a lone Esc arrives in a read of its own and must not consume the following key press.
+        let mut keys = Keys::new((&b"\x1b"[..]).chain(&b"j"[..]));
+        assert_eq!(keys.next().unwrap(), None);
+        assert_eq!(keys.next().unwrap(), Some(Key::Char('j')));
+    }
+}
@@ -0,0 +1,209 @@
+/// Module that computes when puzzles unlock and waits for them.
+/// Puzzles unlock at midnight in the timezone of the Advent of Code server (UTC-5).
+/// Time is read through the [`Clock`] trait so the waiting logic can be exercised with a fake clock.
+use std::{thread, time::Duration};
+
+use chrono::{DateTime, Datelike, FixedOffset, TimeDelta, TimeZone, Utc};
+
+use crate::template::server_time::SERVER_UTC_OFFSET;
+use crate::template::Day;
+
+/// Source of the current time.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep(&mut self, duration: Duration);
+}
+
+/// Wall clock, optionally shifted by a fixed offset to simulate a different start time.
+#[derive(Default)]
+pub struct SystemClock {
+    offset: TimeDelta,
+}
+
+impl SystemClock {
+    /// A clock that starts at `start` and then advances in real time.
+    pub fn starting_at(start: DateTime<Utc>) -> Self {
+        Self {
+            offset: start - Utc::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now() + self.offset
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Clock that only advances when slept on.
+pub struct FakeClock {
+    pub now: DateTime<Utc>,
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        self.now += TimeDelta::from_std(duration).expect("sleep duration is in range");
+    }
+}
+
+fn server_offset() -> FixedOffset {
+    FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600).expect("server offset is valid")
+}
+
+/// Returns the puzzle day that is current at `now`, if it falls between the 1st and 25th of december.
+pub fn day_at(now: DateTime<Utc>) -> Option<Day> {
+    let today = now.with_timezone(&server_offset());
+    if today.month() == 12 && today.day() <= 25 {
+        Day::new(u8::try_from(today.day()).ok()?)
+    } else {
+        None
+    }
+}
+
+/// Returns the time at which the puzzle for `day` of `year` unlocks.
+pub fn unlock_time(year: i32, day: Day) -> Option<DateTime<Utc>> {
+    server_offset()
+        .with_ymd_and_hms(year, 12, u32::from(day.into_inner()), 0, 0, 0)
+        .single()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+/// Returns the next puzzle to unlock strictly after `now`, along with its year and unlock time.
+pub fn next_unlock(now: DateTime<Utc>) -> Option<(i32, Day, DateTime<Utc>)> {
+    let today = now.with_timezone(&server_offset());
+
+    let (year, day) = match (today.month(), today.day()) {
+        (12, day) if day < 25 => (today.year(), day + 1),
+        (12, _) => (today.year() + 1, 1),
+        _ => (today.year(), 1),
+    };
+
+    let day = Day::new(u8::try_from(day).ok()?)?;
+    Some((year, day, unlock_time(year, day)?))
+}
+
+/// Blocks until `target` is reached, calling `on_tick` with the remaining time about once per second.
+pub fn wait_until(
+    clock: &mut impl Clock,
+    target: DateTime<Utc>,
+    mut on_tick: impl FnMut(TimeDelta),
+) {
+    loop {
+        let remaining = target - clock.now();
+        if remaining <= TimeDelta::zero() {
+            break;
+        }
+
+        on_tick(remaining);
+
+        let step = remaining
+            .to_std()
+            .map_or(Duration::ZERO, |r| r.min(Duration::from_secs(1)));
+        clock.sleep(step);
+    }
+}
+
+/// Formats a remaining duration as a countdown, e.g. `1d 02:03:04`.
+pub fn format_countdown(remaining: TimeDelta) -> String {
+    // round up so the countdown reaches zero exactly at unlock.
+    let total = remaining.num_seconds() + i64::from(remaining.subsec_nanos() > 0);
+    let (days, rest) = (total / 86400, total % 86400);
+    let clock = format!(
+        "{:02}:{:02}:{:02}",
+        rest / 3600,
+        rest % 3600 / 60,
+        rest % 60
+    );
+
+    if days > 0 {
+        format!("{days}d {clock}")
+    } else {
+        clock
+    }
+}
+
+#[cfg(all(feature = "test_lib", feature = "today"))]
+mod tests {
+    use chrono::{DateTime, TimeDelta, Utc};
+
+    use super::{day_at, format_countdown, next_unlock, wait_until, FakeClock};
+    use crate::day;
+
+    fn time(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[test]
+    fn finds_current_day_in_server_timezone() {
+        assert_eq!(day_at(time("2024-12-05T04:59:59Z")), Some(day!(4)));
+        assert_eq!(day_at(time("2024-12-05T05:00:00Z")), Some(day!(5)));
+        assert_eq!(day_at(time("2024-12-26T05:00:00Z")), None);
+        assert_eq!(day_at(time("2024-11-30T12:00:00Z")), None);
+    }
+
+    #[test]
+    fn finds_next_unlock() {
+        assert_eq!(
+            next_unlock(time("2024-11-30T23:00:00-05:00")),
+            Some((2024, day!(1), time("2024-12-01T05:00:00Z")))
+        );
+        assert_eq!(
+            next_unlock(time("2024-12-04T23:59:59-05:00")),
+            Some((2024, day!(5), time("2024-12-05T05:00:00Z")))
+        );
+        assert_eq!(
+            next_unlock(time("2024-12-25T00:00:00-05:00")),
+            Some((2025, day!(1), time("2025-12-01T05:00:00Z")))
+        );
+        assert_eq!(
+            next_unlock(time("2025-01-01T00:00:00Z")),
+            Some((2025, day!(1), time("2025-12-01T05:00:00Z")))
+        );
+    }
+
+    #[test]
+    fn waits_until_unlock() {
+        let mut clock = FakeClock {
+            now: time("2024-12-04T23:59:57.500-05:00"),
+        };
+        let target = time("2024-12-05T00:00:00-05:00");
+        let mut ticks = vec![];
+
+        wait_until(&mut clock, target, |remaining| {
+            ticks.push(format_countdown(remaining));
+        });
+
+        assert_eq!(clock.now, target);
+        assert_eq!(ticks, vec!["00:00:03", "00:00:02", "00:00:01"]);
+    }
+
+    #[test]
+    fn does_not_wait_for_past_unlocks() {
+        let now = time("2024-12-05T12:00:00Z");
+        let mut clock = FakeClock { now };
+        let mut ticks = 0;
+
+        wait_until(&mut clock, time("2024-12-05T05:00:00Z"), |_| ticks += 1);
+
+        assert_eq!(ticks, 0);
+        assert_eq!(clock.now, now);
+    }
+
+    #[test]
+    fn formats_countdown() {
+        assert_eq!(format_countdown(TimeDelta::seconds(59)), "00:00:59");
+        assert_eq!(format_countdown(TimeDelta::milliseconds(1)), "00:00:01");
+        assert_eq!(
+            format_countdown(TimeDelta::seconds(86400 + 2 * 3600 + 3 * 60 + 4)),
+            "1d 02:03:04"
+        );
+    }
+}